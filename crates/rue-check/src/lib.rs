@@ -0,0 +1,561 @@
+use std::collections::HashMap;
+
+use rowan::TextRange;
+use rue_syntax::{
+    ast::{
+        AstNode, BinaryExpr, Block, BreakExpr, CallExpr, ContinueExpr, Expr, Function, IfExpr,
+        InterpPart, InterpString, LetStmt, Literal, LiteralKind, NameRef, PrefixExpr, Program,
+        ReturnExpr, Stmt, Type as TypeNode, WhileExpr,
+    },
+    SyntaxKind, SyntaxNode,
+};
+
+mod check_error;
+mod resolve;
+mod scopes;
+mod ty;
+
+pub use check_error::*;
+pub use resolve::*;
+pub use ty::*;
+
+use scopes::Scopes;
+
+/// Type checks a program, returning every error that was found.
+pub fn check(node: &SyntaxNode) -> Vec<CheckError> {
+    let Some(program) = Program::cast(node.clone()) else {
+        return Vec::new();
+    };
+
+    let mut checker = Checker::default();
+    checker.check_program(&program);
+    checker.errors
+}
+
+#[derive(Default)]
+struct Checker {
+    scopes: Scopes<Type>,
+    errors: Vec<CheckError>,
+    /// The return type of the function being checked.
+    return_type: Option<Type>,
+    /// How many loops the expression being checked is nested in.
+    loops: usize,
+}
+
+impl Checker {
+    fn check_program(&mut self, program: &Program) {
+        // Functions can be called before they are defined, so they are declared first.
+        let functions: HashMap<_, _> = program
+            .functions()
+            .filter_map(|function| {
+                Some((
+                    function.name()?.text().to_string(),
+                    self.signature(&function),
+                ))
+            })
+            .collect();
+        self.scopes.push_with(functions);
+
+        for function in program.functions() {
+            self.check_function(&function);
+        }
+    }
+
+    fn signature(&self, function: &Function) -> Type {
+        let params = function
+            .param_list()
+            .map(|param_list| {
+                param_list
+                    .params()
+                    .map(|param| self.resolve_type(param.ty()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Type::Fn {
+            params,
+            ret: Box::new(self.ret_type(function)),
+        }
+    }
+
+    fn ret_type(&self, function: &Function) -> Type {
+        match function.ret_type() {
+            Some(ret_type) => self.resolve_type(ret_type.ty()),
+            None => Type::Unit,
+        }
+    }
+
+    fn resolve_type(&self, ty: Option<TypeNode>) -> Type {
+        match ty {
+            Some(TypeNode::Path(path)) => {
+                let segments: Vec<_> = path.segments().collect();
+                match segments.as_slice() {
+                    [name] => match name.text() {
+                        "Int" => Type::Int,
+                        "Float" => Type::Float,
+                        "Bool" => Type::Bool,
+                        "Str" => Type::Str,
+                        _ => Type::Unknown,
+                    },
+                    _ => Type::Unknown,
+                }
+            }
+            Some(TypeNode::Tuple(tuple)) if tuple.types().next().is_none() => Type::Unit,
+            Some(TypeNode::Paren(paren)) => self.resolve_type(paren.ty()),
+            Some(TypeNode::Tuple(_) | TypeNode::Ref(_)) | None => Type::Unknown,
+        }
+    }
+
+    fn check_function(&mut self, function: &Function) {
+        self.scopes.push();
+
+        for param in function.param_list().iter().flat_map(|list| list.params()) {
+            if let Some(name) = param.name() {
+                let ty = self.resolve_type(param.ty());
+                self.scopes.define(name.text(), ty);
+            }
+        }
+
+        if let Some(body) = function.body() {
+            let expected = self.ret_type(function);
+            self.return_type = Some(expected.clone());
+            let found = self.check_block(&body);
+            let range = body.tail_expr().map_or(body.syntax().text_range(), |expr| {
+                expr.syntax().text_range()
+            });
+            self.expect_type(&expected, &found, range);
+        }
+
+        self.scopes.pop();
+    }
+
+    fn check_block(&mut self, block: &Block) -> Type {
+        self.scopes.push();
+
+        // A block which always returns or breaks before its end has no value.
+        let mut diverges = false;
+
+        for stmt in block.stmts() {
+            match stmt {
+                Stmt::Let(let_stmt) => self.check_let(&let_stmt),
+                Stmt::Expr(expr_stmt) => {
+                    if let Some(expr) = expr_stmt.expr() {
+                        diverges |= self.check_expr(&expr) == Type::Never;
+                    }
+                }
+            }
+        }
+
+        let ty = match block.tail_expr() {
+            Some(expr) => self.check_expr(&expr),
+            None if diverges => Type::Never,
+            None => Type::Unit,
+        };
+
+        self.scopes.pop();
+        ty
+    }
+
+    fn check_let(&mut self, let_stmt: &LetStmt) {
+        let annotation = let_stmt.ty().map(|ty| self.resolve_type(Some(ty)));
+
+        let ty = match (annotation, let_stmt.initializer()) {
+            (Some(expected), Some(initializer)) => {
+                let found = self.check_expr(&initializer);
+                self.expect_type(&expected, &found, initializer.syntax().text_range());
+                expected
+            }
+            (Some(expected), None) => expected,
+            (None, Some(initializer)) => self.check_expr(&initializer),
+            (None, None) => Type::Unknown,
+        };
+
+        if let Some(name) = let_stmt.name() {
+            self.scopes.define(name.text(), ty);
+        }
+    }
+
+    fn check_expr(&mut self, expr: &Expr) -> Type {
+        match expr {
+            Expr::Literal(literal) => check_literal(literal),
+            Expr::NameRef(name_ref) => self.check_name_ref(name_ref),
+            Expr::Paren(paren) => self.check_operand(paren.expr()),
+            Expr::Prefix(prefix) => self.check_prefix(prefix),
+            Expr::Binary(binary) => self.check_binary(binary),
+            Expr::Call(call) => self.check_call(call),
+            Expr::Field(field) => {
+                // There are no struct or tuple types yet, so fields are unknown.
+                self.check_operand(field.receiver());
+                Type::Unknown
+            }
+            Expr::MethodCall(call) => {
+                self.check_operand(call.receiver());
+                for arg in call.arg_list().iter().flat_map(|arg_list| arg_list.args()) {
+                    self.check_expr(&arg);
+                }
+                Type::Unknown
+            }
+            Expr::If(if_expr) => self.check_if(if_expr),
+            Expr::While(while_expr) => self.check_while(while_expr),
+            Expr::InterpString(interp) => self.check_interp_string(interp),
+            Expr::Return(return_expr) => self.check_return(return_expr),
+            Expr::Break(break_expr) => self.check_break(break_expr),
+            Expr::Continue(continue_expr) => self.check_continue(continue_expr),
+        }
+    }
+
+    /// Expressions left out because of parse errors have already been reported by the
+    /// parser, so their type is unknown rather than an error.
+    fn check_operand(&mut self, expr: Option<Expr>) -> Type {
+        match expr {
+            Some(expr) => self.check_expr(&expr),
+            None => Type::Unknown,
+        }
+    }
+
+    fn check_name_ref(&mut self, name_ref: &NameRef) -> Type {
+        // Unresolved names aren't type errors, so they are left to name resolution.
+        name_ref
+            .name()
+            .and_then(|name| self.scopes.lookup(name.text()).cloned())
+            .unwrap_or(Type::Unknown)
+    }
+
+    fn check_prefix(&mut self, prefix: &PrefixExpr) -> Type {
+        let ty = self.check_operand(prefix.expr());
+        let Some(op) = prefix.op() else {
+            return Type::Unknown;
+        };
+
+        match (op.kind(), &ty) {
+            (_, Type::Unknown) => Type::Unknown,
+            (SyntaxKind::Minus, Type::Int | Type::Float) => ty,
+            (SyntaxKind::Not, Type::Int | Type::Bool) => ty,
+            _ => {
+                self.error(
+                    prefix.syntax().text_range(),
+                    format!("cannot apply `{}` to {ty}", op.text()),
+                );
+                Type::Unknown
+            }
+        }
+    }
+
+    fn check_binary(&mut self, binary: &BinaryExpr) -> Type {
+        let lhs = self.check_operand(binary.lhs());
+        let rhs = self.check_operand(binary.rhs());
+        let Some(op) = binary.op() else {
+            return Type::Unknown;
+        };
+
+        if lhs == Type::Unknown || rhs == Type::Unknown {
+            return if is_comparison(op.kind()) {
+                Type::Bool
+            } else {
+                Type::Unknown
+            };
+        }
+
+        let result = if lhs != rhs {
+            None
+        } else if is_comparison(op.kind()) {
+            match op.kind() {
+                SyntaxKind::EqualEquals | SyntaxKind::NotEquals => Some(Type::Bool),
+                _ => matches!(lhs, Type::Int | Type::Float | Type::Str).then_some(Type::Bool),
+            }
+        } else {
+            let supported = match op.kind() {
+                SyntaxKind::Plus => matches!(lhs, Type::Int | Type::Float | Type::Str),
+                SyntaxKind::Minus | SyntaxKind::Star | SyntaxKind::Slash | SyntaxKind::Percent => {
+                    matches!(lhs, Type::Int | Type::Float)
+                }
+                SyntaxKind::Amp | SyntaxKind::Pipe | SyntaxKind::Caret => {
+                    matches!(lhs, Type::Int | Type::Bool)
+                }
+                SyntaxKind::AndAnd | SyntaxKind::OrOr => lhs == Type::Bool,
+                _ => false,
+            };
+            supported.then(|| lhs.clone())
+        };
+
+        result.unwrap_or_else(|| {
+            self.error(
+                binary.syntax().text_range(),
+                format!("cannot apply `{}` to {lhs} and {rhs}", op.text()),
+            );
+            Type::Unknown
+        })
+    }
+
+    fn check_call(&mut self, call: &CallExpr) -> Type {
+        let callee = self.check_operand(call.callee());
+        let args: Vec<_> = call
+            .arg_list()
+            .map(|arg_list| arg_list.args().collect())
+            .unwrap_or_default();
+        let arg_types: Vec<_> = args.iter().map(|arg| self.check_expr(arg)).collect();
+        let range = call.syntax().text_range();
+
+        let (params, ret) = match callee {
+            Type::Fn { params, ret } => (params, ret),
+            Type::Unknown => return Type::Unknown,
+            ty => {
+                self.error(range, format!("cannot call a value of type {ty}"));
+                return Type::Unknown;
+            }
+        };
+
+        if params.len() != args.len() {
+            self.error(
+                range,
+                format!("expected {} arguments, found {}", params.len(), args.len()),
+            );
+        }
+
+        for ((param, arg_type), arg) in params.iter().zip(&arg_types).zip(&args) {
+            self.expect_type(param, arg_type, arg.syntax().text_range());
+        }
+
+        *ret
+    }
+
+    fn check_if(&mut self, if_expr: &IfExpr) -> Type {
+        self.check_condition(if_expr.condition());
+
+        let then_type = match if_expr.then_branch() {
+            Some(block) => self.check_block(&block),
+            None => Type::Unknown,
+        };
+
+        let Some(else_branch) = if_expr.else_branch() else {
+            return Type::Unit;
+        };
+
+        let else_type = if let Some(block) = Block::cast(else_branch.clone()) {
+            self.check_block(&block)
+        } else if let Some(else_if) = IfExpr::cast(else_branch.clone()) {
+            self.check_if(&else_if)
+        } else {
+            Type::Unknown
+        };
+
+        if then_type == Type::Never {
+            return else_type;
+        }
+
+        if !then_type.is_compatible(&else_type) {
+            self.error(
+                if_expr.syntax().text_range(),
+                format!("`if` and `else` have incompatible types {then_type} and {else_type}"),
+            );
+            return Type::Unknown;
+        }
+
+        then_type
+    }
+
+    fn check_while(&mut self, while_expr: &WhileExpr) -> Type {
+        self.check_condition(while_expr.condition());
+
+        if let Some(body) = while_expr.body() {
+            self.loops += 1;
+            self.check_block(&body);
+            self.loops -= 1;
+        }
+
+        Type::Unit
+    }
+
+    /// Any value can be interpolated, so the parts only need to be checked themselves.
+    fn check_interp_string(&mut self, interp: &InterpString) -> Type {
+        for part in interp.parts() {
+            if let InterpPart::Expr(expr) = part {
+                self.check_expr(&expr);
+            }
+        }
+        Type::Str
+    }
+
+    fn check_return(&mut self, return_expr: &ReturnExpr) -> Type {
+        let (found, range) = match return_expr.expr() {
+            Some(expr) => (self.check_expr(&expr), expr.syntax().text_range()),
+            None => (Type::Unit, return_expr.syntax().text_range()),
+        };
+        let expected = self.return_type.clone().unwrap_or(Type::Unknown);
+        self.expect_type(&expected, &found, range);
+        Type::Never
+    }
+
+    fn check_break(&mut self, break_expr: &BreakExpr) -> Type {
+        if let Some(expr) = break_expr.expr() {
+            self.check_expr(&expr);
+        }
+        self.check_in_loop(break_expr.syntax(), "break");
+        Type::Never
+    }
+
+    fn check_continue(&mut self, continue_expr: &ContinueExpr) -> Type {
+        self.check_in_loop(continue_expr.syntax(), "continue");
+        Type::Never
+    }
+
+    fn check_in_loop(&mut self, node: &SyntaxNode, keyword: &str) {
+        if self.loops == 0 {
+            self.error(node.text_range(), format!("`{keyword}` outside of a loop"));
+        }
+    }
+
+    fn check_condition(&mut self, condition: Option<Expr>) {
+        if let Some(condition) = condition {
+            let ty = self.check_expr(&condition);
+            self.expect_type(&Type::Bool, &ty, condition.syntax().text_range());
+        }
+    }
+
+    fn expect_type(&mut self, expected: &Type, found: &Type, range: TextRange) {
+        if !found.is_compatible(expected) {
+            self.error(range, format!("expected {expected}, found {found}"));
+        }
+    }
+
+    fn error(&mut self, range: TextRange, message: String) {
+        self.errors.push(CheckError::new(range, message));
+    }
+}
+
+fn check_literal(literal: &Literal) -> Type {
+    match literal.kind() {
+        Some(LiteralKind::Int) => Type::Int,
+        Some(LiteralKind::Float) => Type::Float,
+        Some(LiteralKind::Bool) => Type::Bool,
+        Some(LiteralKind::String) => Type::Str,
+        Some(LiteralKind::Char | LiteralKind::ByteString | LiteralKind::Byte) | None => {
+            Type::Unknown
+        }
+    }
+}
+
+fn is_comparison(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::EqualEquals
+            | SyntaxKind::NotEquals
+            | SyntaxKind::LessThan
+            | SyntaxKind::GreaterThan
+            | SyntaxKind::LessThanEquals
+            | SyntaxKind::GreaterThanEquals
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use rue_parser::parse_text;
+
+    use super::*;
+
+    fn check_str(source: &str) -> Vec<String> {
+        check(&parse_text(source))
+            .into_iter()
+            .map(|error| format!("{:?} {}", error.range, error.message))
+            .collect()
+    }
+
+    #[test]
+    fn check_clean_program() {
+        let source = "
+            fn add(a: Int, b: Int) -> Int { a + b }
+
+            fn main() {
+                let x: Int = add(1, 2);
+                let ok = x > 2 && true;
+                if ok { add(x, 1); } else {}
+            }
+        ";
+        assert!(check_str(source).is_empty());
+    }
+
+    #[test]
+    fn check_binary_mismatch() {
+        assert_eq!(
+            check_str("fn main() { 1 + \"x\"; }"),
+            ["12..19 cannot apply `+` to Int and Str"]
+        );
+    }
+
+    #[test]
+    fn check_call_non_function() {
+        assert_eq!(
+            check_str("fn main() { let x = 1; x(); }"),
+            ["23..26 cannot call a value of type Int"]
+        );
+    }
+
+    #[test]
+    fn check_arity() {
+        assert_eq!(
+            check_str("fn add(a: Int, b: Int) -> Int { a + b }\nfn main() -> Int { add(1) }"),
+            ["59..66 expected 2 arguments, found 1"]
+        );
+    }
+
+    #[test]
+    fn check_annotations() {
+        assert_eq!(
+            check_str("fn main() -> Bool { let x: Str = 1; 2 }"),
+            [
+                "33..34 expected Str, found Int",
+                "36..38 expected Bool, found Int"
+            ]
+        );
+    }
+
+    #[test]
+    fn check_return() {
+        assert!(check_str("fn f() -> Int { return 1; }").is_empty());
+        assert!(check_str("fn f(a: Bool) -> Int { if a { return 1; } 2 }").is_empty());
+        assert!(check_str("fn f(a: Bool) -> Int { if a { return 1 } else { 2 } }").is_empty());
+        assert!(check_str("fn f() { return; }").is_empty());
+        assert_eq!(
+            check_str("fn f() -> Int { return true; }"),
+            ["23..27 expected Int, found Bool"]
+        );
+        assert_eq!(
+            check_str("fn f() -> Int { return; }"),
+            ["16..22 expected Int, found ()"]
+        );
+    }
+
+    #[test]
+    fn check_break_continue() {
+        assert!(check_str("fn f() { while true { break; continue; } }").is_empty());
+        assert_eq!(
+            check_str("fn f() { break; continue; }"),
+            [
+                "9..14 `break` outside of a loop",
+                "16..24 `continue` outside of a loop"
+            ]
+        );
+    }
+
+    #[test]
+    fn check_interp_string() {
+        assert!(check_str(r#"fn f() -> Str { "a ${1} b" }"#).is_empty());
+        assert_eq!(
+            check_str(r#"fn f() -> Int { "a ${1 + true}" }"#),
+            [
+                "21..29 cannot apply `+` to Int and Bool",
+                "16..32 expected Int, found Str"
+            ]
+        );
+    }
+
+    #[test]
+    fn check_field_access() {
+        assert!(check_str("fn f(a: Int) -> Int { a.b }").is_empty());
+        assert!(check_str("fn f(a: Int) -> Int { a.b(1).0 }").is_empty());
+        assert_eq!(
+            check_str("fn f(a: Int) { a.b(1 + true); }"),
+            ["19..27 cannot apply `+` to Int and Bool"]
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+
+use rue_syntax::{
+    ast::{AstNode, Function, LetStmt, NameRef, Param, Program},
+    SyntaxKind, SyntaxNode,
+};
+
+use crate::{CheckError, Scopes};
+
+/// The declaration that a name refers to.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Binding {
+    Function(Function),
+    Param(Param),
+    Let(LetStmt),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Resolution {
+    bindings: HashMap<NameRef, Binding>,
+    errors: Vec<CheckError>,
+}
+
+impl Resolution {
+    pub fn lookup(&self, name_ref: &NameRef) -> Option<&Binding> {
+        self.bindings.get(name_ref)
+    }
+
+    pub fn errors(&self) -> &[CheckError] {
+        &self.errors
+    }
+}
+
+/// Resolves every name in the program to the declaration it refers to.
+pub fn resolve(program: &Program) -> Resolution {
+    let mut resolver = Resolver::default();
+
+    // Functions are visible everywhere, including before their definition.
+    let functions = program
+        .functions()
+        .filter_map(|function| {
+            let name = function.name()?.text().to_string();
+            Some((name, Binding::Function(function)))
+        })
+        .collect();
+    resolver.scopes.push_with(functions);
+
+    for function in program.functions() {
+        resolver.resolve_node(function.syntax());
+    }
+
+    resolver.resolution
+}
+
+#[derive(Default)]
+struct Resolver {
+    scopes: Scopes<Binding>,
+    resolution: Resolution,
+}
+
+impl Resolver {
+    fn resolve_node(&mut self, node: &SyntaxNode) {
+        match node.kind() {
+            SyntaxKind::Function | SyntaxKind::Block => {
+                self.scopes.push();
+                self.resolve_children(node);
+                self.scopes.pop();
+            }
+            SyntaxKind::Param => {
+                let param = Param::cast(node.clone()).expect("node is a parameter");
+                if let Some(name) = param.name() {
+                    self.scopes.define(name.text(), Binding::Param(param));
+                }
+            }
+            SyntaxKind::LetStmt => {
+                // The initializer can't refer to the variable being declared.
+                self.resolve_children(node);
+
+                let let_stmt = LetStmt::cast(node.clone()).expect("node is a `let` statement");
+                if let Some(name) = let_stmt.name() {
+                    self.scopes.define(name.text(), Binding::Let(let_stmt));
+                }
+            }
+            SyntaxKind::NameRef => {
+                let name_ref = NameRef::cast(node.clone()).expect("node is a name");
+                self.resolve_name_ref(name_ref);
+            }
+            _ => self.resolve_children(node),
+        }
+    }
+
+    fn resolve_children(&mut self, node: &SyntaxNode) {
+        for child in node.children() {
+            self.resolve_node(&child);
+        }
+    }
+
+    fn resolve_name_ref(&mut self, name_ref: NameRef) {
+        let Some(name) = name_ref.name() else {
+            return;
+        };
+
+        match self.scopes.lookup(name.text()) {
+            Some(binding) => {
+                let binding = binding.clone();
+                self.resolution.bindings.insert(name_ref, binding);
+            }
+            None => self.resolution.errors.push(CheckError::new(
+                name.text_range(),
+                format!("cannot find `{}` in this scope", name.text()),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rowan::TextRange;
+    use rue_parser::parse_text;
+
+    use super::*;
+
+    fn resolve_str(source: &str) -> (Program, Resolution) {
+        let program = Program::cast(parse_text(source)).unwrap();
+        let resolution = resolve(&program);
+        (program, resolution)
+    }
+
+    fn name_refs(program: &Program) -> Vec<NameRef> {
+        program
+            .syntax()
+            .descendants()
+            .filter_map(NameRef::cast)
+            .collect()
+    }
+
+    #[test]
+    fn resolve_shadowing() {
+        let (program, resolution) = resolve_str("fn main(x: Int) { let x = x; let x = 2; x }");
+        assert!(resolution.errors().is_empty());
+
+        let refs = name_refs(&program);
+        assert!(matches!(
+            resolution.lookup(&refs[0]),
+            Some(Binding::Param(_))
+        ));
+
+        let Some(Binding::Let(let_stmt)) = resolution.lookup(&refs[1]) else {
+            panic!("expected a `let` binding");
+        };
+        assert_eq!(let_stmt.syntax().text().to_string(), "let x = 2; ");
+    }
+
+    #[test]
+    fn resolve_forward_function() {
+        let (program, resolution) = resolve_str("fn main() { helper() }\nfn helper() {}");
+        assert!(resolution.errors().is_empty());
+
+        let Some(Binding::Function(function)) = resolution.lookup(&name_refs(&program)[0]) else {
+            panic!("expected a function binding");
+        };
+        assert_eq!(function.name().unwrap().text(), "helper");
+    }
+
+    #[test]
+    fn resolve_use_before_def() {
+        let (program, resolution) = resolve_str("fn main() { let y = x; let x = 1; }");
+
+        assert_eq!(resolution.lookup(&name_refs(&program)[0]), None);
+        assert_eq!(
+            resolution.errors(),
+            [CheckError::new(
+                TextRange::new(20.into(), 21.into()),
+                "cannot find `x` in this scope"
+            )]
+        );
+    }
+
+    #[test]
+    fn resolve_block_scope() {
+        let (_, resolution) = resolve_str("fn main() { if true { let x = 1; } x }");
+        assert_eq!(resolution.errors().len(), 1);
+    }
+}
@@ -0,0 +1,40 @@
+use std::collections::HashMap;
+
+/// The names in scope from the outermost scope to the innermost, and what each one
+/// refers to.
+#[derive(Debug, Clone)]
+pub(crate) struct Scopes<T> {
+    scopes: Vec<HashMap<String, T>>,
+}
+
+impl<T> Scopes<T> {
+    pub fn push(&mut self) {
+        self.push_with(HashMap::new());
+    }
+
+    pub fn push_with(&mut self, scope: HashMap<String, T>) {
+        self.scopes.push(scope);
+    }
+
+    pub fn pop(&mut self) {
+        self.scopes.pop();
+    }
+
+    /// Binds a name in the innermost scope, shadowing any previous binding.
+    pub fn define(&mut self, name: &str, value: T) {
+        self.scopes
+            .last_mut()
+            .expect("there is always a scope")
+            .insert(name.to_string(), value);
+    }
+
+    pub fn lookup(&self, name: &str) -> Option<&T> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name))
+    }
+}
+
+impl<T> Default for Scopes<T> {
+    fn default() -> Self {
+        Self { scopes: Vec::new() }
+    }
+}
@@ -0,0 +1,68 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Type {
+    Int,
+    Float,
+    Bool,
+    Str,
+    Unit,
+    Fn {
+        params: Vec<Type>,
+        ret: Box<Type>,
+    },
+    /// A type that couldn't be determined, which is compatible with every type
+    /// so that one mistake doesn't cause a cascade of errors.
+    Unknown,
+    /// The type of an expression which never finishes, such as `return`. It's also
+    /// compatible with every type, since no value of it will ever exist.
+    Never,
+}
+
+impl Type {
+    /// Whether a value of this type can be used where the other is expected.
+    pub fn is_compatible(&self, other: &Type) -> bool {
+        match (self, other) {
+            (Type::Unknown | Type::Never, _) | (_, Type::Unknown | Type::Never) => true,
+            (
+                Type::Fn { params, ret },
+                Type::Fn {
+                    params: other_params,
+                    ret: other_ret,
+                },
+            ) => {
+                params.len() == other_params.len()
+                    && params
+                        .iter()
+                        .zip(other_params)
+                        .all(|(param, other)| param.is_compatible(other))
+                    && ret.is_compatible(other_ret)
+            }
+            _ => self == other,
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int => write!(f, "Int"),
+            Self::Float => write!(f, "Float"),
+            Self::Bool => write!(f, "Bool"),
+            Self::Str => write!(f, "Str"),
+            Self::Unit => write!(f, "()"),
+            Self::Fn { params, ret } => {
+                write!(f, "fn(")?;
+                for (i, param) in params.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{param}")?;
+                }
+                write!(f, ") -> {ret}")
+            }
+            Self::Unknown => write!(f, "{{unknown}}"),
+            Self::Never => write!(f, "!"),
+        }
+    }
+}
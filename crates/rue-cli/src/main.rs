@@ -0,0 +1,142 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use clap::{Parser, Subcommand};
+use rue_lexer::{Lexer, LineIndex};
+use rue_parser::{parse_text_with_errors, Parse};
+
+#[derive(Debug, Parser)]
+#[command(name = "rue", about = "Tools for working with Rue source files")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Print the tokens of a file with their spans.
+    Lex { file: PathBuf },
+    /// Print the syntax tree of a file.
+    Parse {
+        file: PathBuf,
+        /// Print the tree as JSON instead.
+        #[arg(long)]
+        json: bool,
+    },
+    /// Format a file in place.
+    Fmt {
+        file: PathBuf,
+        /// Print the formatted file instead, and fail if it isn't already formatted.
+        #[arg(long)]
+        check: bool,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let file = match &cli.command {
+        Command::Lex { file } | Command::Parse { file, .. } | Command::Fmt { file, .. } => file,
+    };
+
+    let source = match fs::read_to_string(file) {
+        Ok(source) => source,
+        Err(error) => {
+            eprintln!("error: cannot read {}: {error}", file.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let success = match cli.command {
+        Command::Lex { file } => lex(&file, &source),
+        Command::Parse { file, json } => parse(&file, &source, json),
+        Command::Fmt { file, check } => fmt(&file, &source, check),
+    };
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn lex(file: &Path, source: &str) -> bool {
+    let mut lexer = Lexer::new(source);
+
+    for token in lexer.by_ref() {
+        println!("{token}");
+    }
+
+    let errors = lexer.take_diagnostics();
+    let index = LineIndex::new(source);
+
+    for error in &errors {
+        report(file, &index, error.span.start, &error.message);
+    }
+
+    errors.is_empty()
+}
+
+fn parse(file: &Path, source: &str, json: bool) -> bool {
+    let parse = parse_text_with_errors(source);
+
+    if json {
+        let json = rue_syntax::to_json(&parse.node);
+        println!("{}", serde_json::to_string_pretty(&json).unwrap());
+    } else {
+        print!("{:#?}", parse.node);
+    }
+
+    report_parse_errors(file, source, &parse)
+}
+
+fn fmt(file: &Path, source: &str, check: bool) -> bool {
+    let parse = parse_text_with_errors(source);
+
+    // Formatting broken code could make it worse, so it's left alone.
+    if !report_parse_errors(file, source, &parse) {
+        return false;
+    }
+
+    let formatted = rue_fmt::format(&parse.node);
+
+    if check {
+        print!("{formatted}");
+
+        if formatted != source {
+            eprintln!("{} is not formatted", file.display());
+            return false;
+        }
+    } else if formatted != source {
+        if let Err(error) = fs::write(file, formatted) {
+            eprintln!("error: cannot write {}: {error}", file.display());
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Prints the parse errors, returning whether there were none.
+fn report_parse_errors(file: &Path, source: &str, parse: &Parse) -> bool {
+    let index = LineIndex::new(source);
+
+    for error in &parse.errors {
+        report(file, &index, error.range.start().into(), &error.message);
+    }
+
+    parse.errors.is_empty()
+}
+
+fn report(file: &Path, index: &LineIndex, offset: usize, message: &str) {
+    let pos = index.line_col(offset);
+    eprintln!(
+        "{}:{}:{}: error: {message}",
+        file.display(),
+        pos.line + 1,
+        pos.col + 1
+    );
+}
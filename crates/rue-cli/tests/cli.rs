@@ -0,0 +1,120 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Output},
+};
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+fn rue(args: &[&str], file: &Path) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_rue"))
+        .args(args)
+        .arg(file)
+        .output()
+        .unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8(output.stderr.clone()).unwrap()
+}
+
+#[test]
+fn lex_prints_tokens() {
+    let output = rue(&["lex"], &fixture("formatted.rue"));
+    assert!(output.status.success());
+    assert!(stdout(&output).starts_with("Fn@0..2 \"fn\"\nWhitespace { newlines: 0 }@2..3 \" \"\n"));
+}
+
+#[test]
+fn lex_reports_errors() {
+    let file = fixture("lex_error.rue");
+    let output = rue(&["lex"], &file);
+    assert!(!output.status.success());
+    assert_eq!(
+        stderr(&output),
+        format!(
+            "{}:2:5: error: unterminated string literal\n",
+            file.display()
+        )
+    );
+}
+
+#[test]
+fn parse_prints_tree() {
+    let output = rue(&["parse"], &fixture("formatted.rue"));
+    assert!(output.status.success());
+    assert!(stdout(&output).starts_with("Program@0..82\n  Function@0..45\n"));
+}
+
+#[test]
+fn parse_prints_json() {
+    let output = rue(&["parse", "--json"], &fixture("formatted.rue"));
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_str(&stdout(&output)).unwrap();
+    assert_eq!(json["kind"], "Program");
+    assert_eq!(json["range"], serde_json::json!([0, 82]));
+    assert_eq!(json["children"][0]["kind"], "Function");
+    assert_eq!(json["children"][0]["children"][0]["text"], "fn");
+}
+
+#[test]
+fn parse_reports_errors() {
+    let file = fixture("parse_error.rue");
+    let output = rue(&["parse"], &file);
+    assert!(!output.status.success());
+    assert_eq!(
+        stderr(&output),
+        format!("{}:2:13: error: expected expression\n", file.display())
+    );
+}
+
+#[test]
+fn fmt_check_formatted() {
+    let file = fixture("formatted.rue");
+    let output = rue(&["fmt", "--check"], &file);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), fs::read_to_string(file).unwrap());
+}
+
+#[test]
+fn fmt_check_unformatted() {
+    let output = rue(&["fmt", "--check"], &fixture("unformatted.rue"));
+    assert!(!output.status.success());
+    assert_eq!(
+        stdout(&output),
+        fs::read_to_string(fixture("formatted.rue")).unwrap()
+    );
+}
+
+#[test]
+fn fmt_in_place() {
+    let file = std::env::temp_dir().join(format!("rue-cli-fmt-{}.rue", std::process::id()));
+    fs::copy(fixture("unformatted.rue"), &file).unwrap();
+
+    let output = rue(&["fmt"], &file);
+    let formatted = fs::read_to_string(&file).unwrap();
+    fs::remove_file(&file).unwrap();
+
+    assert!(output.status.success());
+    assert_eq!(
+        formatted,
+        fs::read_to_string(fixture("formatted.rue")).unwrap()
+    );
+}
+
+#[test]
+fn fmt_refuses_parse_errors() {
+    let file = fixture("parse_error.rue");
+    let output = rue(&["fmt", "--check"], &file);
+    assert!(!output.status.success());
+    assert!(stdout(&output).is_empty());
+}
@@ -0,0 +1,30 @@
+use rowan::TextRange;
+use rue_check::CheckError;
+use rue_parser::ParseError;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Diagnostic {
+    pub range: TextRange,
+    pub message: String,
+}
+
+impl Diagnostic {
+    pub fn new(range: TextRange, message: impl Into<String>) -> Self {
+        Self {
+            range,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<ParseError> for Diagnostic {
+    fn from(error: ParseError) -> Self {
+        Self::new(error.range, error.message)
+    }
+}
+
+impl From<CheckError> for Diagnostic {
+    fn from(error: CheckError) -> Self {
+        Self::new(error.range, error.message)
+    }
+}
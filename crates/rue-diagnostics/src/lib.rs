@@ -0,0 +1,206 @@
+use std::fmt::Write;
+
+use rue_lexer::{LineCol, LineIndex};
+
+mod diagnostic;
+
+pub use diagnostic::*;
+
+/// Renders each diagnostic with the lines of source it refers to, underlining
+/// the span with carets.
+pub fn render(source: &str, diagnostics: &[Diagnostic]) -> String {
+    let index = LineIndex::new(source);
+    let lines: Vec<&str> = source.lines().collect();
+    let mut output = String::new();
+
+    for (i, diagnostic) in diagnostics.iter().enumerate() {
+        if i > 0 {
+            output.push('\n');
+        }
+        render_diagnostic(&mut output, &index, &lines, diagnostic);
+    }
+
+    output
+}
+
+fn render_diagnostic(
+    output: &mut String,
+    index: &LineIndex,
+    lines: &[&str],
+    diagnostic: &Diagnostic,
+) {
+    let start = index.line_col(diagnostic.range.start().into());
+    let mut end = index.line_col(diagnostic.range.end().into());
+
+    // A span ending with a newline shouldn't underline the start of the next line.
+    if end.line > start.line && end.col == 0 {
+        let line = end.line - 1;
+        end = LineCol {
+            line,
+            col: line_text(lines, line).chars().count() as u32,
+        };
+    }
+
+    let width = (end.line + 1).to_string().len();
+    let pad = " ".repeat(width);
+
+    writeln!(output, "error: {}", diagnostic.message).unwrap();
+    writeln!(output, "{pad}--> {}:{}", start.line + 1, start.col + 1).unwrap();
+    writeln!(output, "{pad} |").unwrap();
+
+    for line in start.line..=end.line {
+        let text = line_text(lines, line);
+        let from = if line == start.line {
+            start.col
+        } else {
+            text.chars().take_while(|c| c.is_whitespace()).count() as u32
+        };
+        let to = if line == end.line {
+            end.col
+        } else {
+            text.chars().count() as u32
+        };
+
+        writeln!(output, "{:>width$} | {text}", line + 1).unwrap();
+
+        // Empty spans still get a caret on their first line, so they can be seen.
+        let carets = if line == start.line {
+            to.saturating_sub(from).max(1)
+        } else {
+            to.saturating_sub(from)
+        };
+
+        if carets > 0 {
+            let indent = indentation(text, from as usize);
+            let carets = "^".repeat(carets as usize);
+            writeln!(output, "{pad} | {indent}{carets}").unwrap();
+        }
+    }
+}
+
+fn line_text<'a>(lines: &[&'a str], line: u32) -> &'a str {
+    lines.get(line as usize).copied().unwrap_or("")
+}
+
+/// Spaces to line up with the given column, keeping tabs so the carets line up
+/// however wide they are displayed.
+fn indentation(text: &str, col: usize) -> String {
+    text.chars()
+        .chain(std::iter::repeat(' '))
+        .take(col)
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+    use rowan::TextRange;
+    use rue_parser::parse_text_with_errors;
+
+    use super::*;
+
+    fn check(source: &str, diagnostics: &[Diagnostic], expected: Expect) {
+        expected.assert_eq(&render(source, diagnostics));
+    }
+
+    fn diagnostic(start: u32, end: u32, message: &str) -> Diagnostic {
+        Diagnostic::new(TextRange::new(start.into(), end.into()), message)
+    }
+
+    #[test]
+    fn render_parse_errors() {
+        let source = "fn main() {\n    let x = ;\n}\n";
+        let diagnostics: Vec<Diagnostic> = parse_text_with_errors(source)
+            .errors
+            .into_iter()
+            .map(Diagnostic::from)
+            .collect();
+
+        check(
+            source,
+            &diagnostics,
+            expect![[r#"
+                error: expected expression
+                 --> 2:13
+                  |
+                2 |     let x = ;
+                  |             ^
+            "#]],
+        );
+    }
+
+    #[test]
+    fn render_type_error() {
+        check(
+            "fn main() {\n\tlet x: Int = \"hello\";\n}",
+            &[diagnostic(26, 33, "expected Int, found Str")],
+            expect![[r#"
+                error: expected Int, found Str
+                 --> 2:15
+                  |
+                2 | 	let x: Int = "hello";
+                  | 	             ^^^^^^^
+            "#]],
+        );
+    }
+
+    #[test]
+    fn render_multiline_span() {
+        check(
+            "fn main() {\n    if x {\n        1\n    }\n}",
+            &[diagnostic(16, 39, "expected Bool, found Int")],
+            expect![[r#"
+                error: expected Bool, found Int
+                 --> 2:5
+                  |
+                2 |     if x {
+                  |     ^^^^^^
+                3 |         1
+                  |         ^
+                4 |     }
+                  |     ^
+            "#]],
+        );
+    }
+
+    #[test]
+    fn render_end_of_file() {
+        check(
+            "fn main() {\n    1 +",
+            &[
+                diagnostic(19, 19, "expected expression"),
+                diagnostic(19, 19, "expected close brace, found end of file"),
+            ],
+            expect![[r#"
+                error: expected expression
+                 --> 2:8
+                  |
+                2 |     1 +
+                  |        ^
+
+                error: expected close brace, found end of file
+                 --> 2:8
+                  |
+                2 |     1 +
+                  |        ^
+            "#]],
+        );
+    }
+
+    #[test]
+    fn render_many_lines() {
+        let source = "\n".repeat(9) + "fn main(";
+        check(
+            &source,
+            &[diagnostic(9, 17, "unclosed function")],
+            expect![[r#"
+                error: unclosed function
+                  --> 10:1
+                   |
+                10 | fn main(
+                   | ^^^^^^^^
+            "#]],
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use std::{collections::HashMap, rc::Rc};
+
+use rue_syntax::ast::{Function, Program};
+
+use crate::Value;
+
+/// How many calls can be nested, so that runaway recursion is reported as an error
+/// instead of overflowing the stack.
+pub const MAX_CALL_DEPTH: usize = 1000;
+
+/// The functions of the program, and the variables in scope from the outermost
+/// scope to the innermost.
+#[derive(Debug, Clone)]
+pub struct Env {
+    functions: Rc<HashMap<String, Function>>,
+    scopes: Vec<HashMap<String, Value>>,
+    depth: usize,
+}
+
+impl Env {
+    pub fn new() -> Self {
+        Self {
+            functions: Rc::default(),
+            scopes: vec![HashMap::new()],
+            depth: 0,
+        }
+    }
+
+    /// Creates an environment containing the top-level functions of a program.
+    pub fn from_program(program: &Program) -> Self {
+        let functions = program
+            .functions()
+            .filter_map(|function| Some((function.name()?.text().to_string(), function)))
+            .collect();
+
+        Self {
+            functions: Rc::new(functions),
+            scopes: vec![HashMap::new()],
+            depth: 0,
+        }
+    }
+
+    /// Creates an environment for a function call, which can see the functions
+    /// but none of the caller's variables.
+    pub fn call_frame(&self) -> Self {
+        Self {
+            functions: self.functions.clone(),
+            scopes: vec![HashMap::new()],
+            depth: self.depth + 1,
+        }
+    }
+
+    /// How many calls are in progress.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn push_scope(&mut self) {
+        self.scopes.push(HashMap::new());
+    }
+
+    pub fn pop_scope(&mut self) {
+        self.scopes.pop();
+    }
+
+    /// Binds a name in the innermost scope, shadowing any previous binding.
+    pub fn define(&mut self, name: impl Into<String>, value: Value) {
+        self.scopes
+            .last_mut()
+            .expect("there is always a scope")
+            .insert(name.into(), value);
+    }
+
+    pub fn lookup(&self, name: &str) -> Option<&Value> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name))
+    }
+
+    pub fn function(&self, name: &str) -> Option<&Function> {
+        self.functions.get(name)
+    }
+}
+
+impl Default for Env {
+    fn default() -> Self {
+        Self::new()
+    }
+}
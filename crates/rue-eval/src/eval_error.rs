@@ -0,0 +1,66 @@
+use std::fmt;
+
+use rowan::TextRange;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EvalError {
+    /// The syntax tree is incomplete, because the source had parse errors.
+    Missing(TextRange),
+    Unsupported(TextRange),
+    InvalidLiteral(TextRange),
+    UnboundName {
+        name: String,
+        range: TextRange,
+    },
+    ArityMismatch {
+        expected: usize,
+        found: usize,
+        range: TextRange,
+    },
+    TypeMismatch {
+        message: String,
+        range: TextRange,
+    },
+    DivisionByZero(TextRange),
+    Overflow(TextRange),
+    /// A `break` or `continue` which isn't inside a loop.
+    OutsideLoop(TextRange),
+    /// A call nested more than [`MAX_CALL_DEPTH`](crate::MAX_CALL_DEPTH) deep.
+    StackOverflow(TextRange),
+}
+
+impl EvalError {
+    pub fn range(&self) -> TextRange {
+        match self {
+            Self::Missing(range)
+            | Self::Unsupported(range)
+            | Self::InvalidLiteral(range)
+            | Self::UnboundName { range, .. }
+            | Self::ArityMismatch { range, .. }
+            | Self::TypeMismatch { range, .. }
+            | Self::DivisionByZero(range)
+            | Self::Overflow(range)
+            | Self::OutsideLoop(range)
+            | Self::StackOverflow(range) => *range,
+        }
+    }
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Missing(_) => write!(f, "cannot evaluate incomplete syntax"),
+            Self::Unsupported(_) => write!(f, "cannot evaluate this syntax yet"),
+            Self::InvalidLiteral(_) => write!(f, "invalid literal"),
+            Self::UnboundName { name, .. } => write!(f, "cannot find `{name}` in this scope"),
+            Self::ArityMismatch {
+                expected, found, ..
+            } => write!(f, "expected {expected} arguments, found {found}"),
+            Self::TypeMismatch { message, .. } => write!(f, "{message}"),
+            Self::DivisionByZero(_) => write!(f, "division by zero"),
+            Self::Overflow(_) => write!(f, "integer overflow"),
+            Self::OutsideLoop(_) => write!(f, "`break` and `continue` must be inside a loop"),
+            Self::StackOverflow(_) => write!(f, "too many nested calls"),
+        }
+    }
+}
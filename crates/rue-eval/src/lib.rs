@@ -0,0 +1,750 @@
+use rowan::TextRange;
+use rue_syntax::{
+    ast::{
+        AstNode, BinaryExpr, Block, BreakExpr, CallExpr, Expr, IfExpr, InterpPart, InterpString,
+        LetStmt, Literal, LiteralKind, NameRef, PrefixExpr, Program, ReturnExpr, Stmt, WhileExpr,
+    },
+    SyntaxKind, SyntaxNode, SyntaxToken,
+};
+
+mod env;
+mod eval_error;
+mod value;
+
+pub use env::*;
+pub use eval_error::*;
+pub use value::*;
+
+/// Why evaluation stopped before reaching the end of an expression. Jumps unwind like
+/// errors, until they reach the function or loop which they leave.
+enum Unwind {
+    Error(EvalError),
+    Return(Value),
+    Break(TextRange),
+    Continue(TextRange),
+}
+
+impl From<EvalError> for Unwind {
+    fn from(error: EvalError) -> Self {
+        Self::Error(error)
+    }
+}
+
+/// Ends the unwinding at a function boundary, which a `return` stops at, but which a
+/// `break` or `continue` can't leave.
+fn finish_call(result: Result<Value, Unwind>) -> Result<Value, EvalError> {
+    match result {
+        Ok(value) | Err(Unwind::Return(value)) => Ok(value),
+        Err(Unwind::Error(error)) => Err(error),
+        Err(Unwind::Break(range) | Unwind::Continue(range)) => Err(EvalError::OutsideLoop(range)),
+    }
+}
+
+/// Runs a program by calling its `main` function.
+pub fn run(node: &SyntaxNode) -> Result<Value, EvalError> {
+    let program = Program::cast(node.clone()).ok_or(EvalError::Unsupported(node.text_range()))?;
+    let env = Env::from_program(&program);
+
+    let main = env.function("main").ok_or_else(|| EvalError::UnboundName {
+        name: "main".to_string(),
+        range: node.text_range(),
+    })?;
+    let body = main
+        .body()
+        .ok_or(EvalError::Missing(main.syntax().text_range()))?;
+
+    finish_call(eval_block(&body, &mut env.call_frame()))
+}
+
+/// Evaluates an expression or block node in an empty environment.
+pub fn eval(node: &SyntaxNode) -> Result<Value, EvalError> {
+    eval_in(node, &mut Env::new())
+}
+
+pub fn eval_in(node: &SyntaxNode, env: &mut Env) -> Result<Value, EvalError> {
+    finish_call(eval_node(node, env))
+}
+
+fn eval_node(node: &SyntaxNode, env: &mut Env) -> Result<Value, Unwind> {
+    if let Some(block) = Block::cast(node.clone()) {
+        return eval_block(&block, env);
+    }
+
+    let expr = Expr::cast(node.clone()).ok_or(EvalError::Unsupported(node.text_range()))?;
+    eval_expr(&expr, env)
+}
+
+fn eval_expr(expr: &Expr, env: &mut Env) -> Result<Value, Unwind> {
+    match expr {
+        Expr::Literal(literal) => Ok(eval_literal(literal)?),
+        Expr::NameRef(name_ref) => Ok(eval_name_ref(name_ref, env)?),
+        Expr::Paren(paren) => eval_operand(paren.syntax(), paren.expr(), env),
+        Expr::Prefix(prefix) => eval_prefix(prefix, env),
+        Expr::Binary(binary) => eval_binary(binary, env),
+        Expr::Call(call) => eval_call(call, env),
+        Expr::Field(_) | Expr::MethodCall(_) => {
+            Err(EvalError::Unsupported(expr.syntax().text_range()).into())
+        }
+        Expr::If(if_expr) => eval_if(if_expr, env),
+        Expr::While(while_expr) => eval_while(while_expr, env),
+        Expr::InterpString(interp) => eval_interp_string(interp, env),
+        Expr::Return(return_expr) => eval_return(return_expr, env),
+        Expr::Break(break_expr) => eval_break(break_expr, env),
+        Expr::Continue(continue_expr) => Err(Unwind::Continue(continue_expr.syntax().text_range())),
+    }
+}
+
+/// Evaluates a child expression, which may be missing if there were parse errors.
+fn eval_operand(parent: &SyntaxNode, expr: Option<Expr>, env: &mut Env) -> Result<Value, Unwind> {
+    let expr = expr.ok_or(EvalError::Missing(parent.text_range()))?;
+    eval_expr(&expr, env)
+}
+
+fn eval_block(block: &Block, env: &mut Env) -> Result<Value, Unwind> {
+    env.push_scope();
+    let result = eval_block_contents(block, env);
+    env.pop_scope();
+    result
+}
+
+fn eval_block_contents(block: &Block, env: &mut Env) -> Result<Value, Unwind> {
+    for stmt in block.stmts() {
+        match stmt {
+            Stmt::Let(let_stmt) => eval_let(&let_stmt, env)?,
+            Stmt::Expr(expr_stmt) => {
+                eval_operand(expr_stmt.syntax(), expr_stmt.expr(), env)?;
+            }
+        }
+    }
+
+    match block.tail_expr() {
+        Some(expr) => eval_expr(&expr, env),
+        None => Ok(Value::Unit),
+    }
+}
+
+fn eval_call(call: &CallExpr, env: &mut Env) -> Result<Value, Unwind> {
+    let range = call.syntax().text_range();
+
+    // Functions aren't values, so only calls to them by name are supported.
+    let Some(Expr::NameRef(callee)) = call.callee() else {
+        return Err(EvalError::Unsupported(range).into());
+    };
+    let name = callee.name().ok_or(EvalError::Missing(range))?;
+    let function = env
+        .function(name.text())
+        .cloned()
+        .ok_or_else(|| EvalError::UnboundName {
+            name: name.text().to_string(),
+            range: name.text_range(),
+        })?;
+
+    let params: Vec<_> = function
+        .param_list()
+        .map(|param_list| param_list.params().collect())
+        .unwrap_or_default();
+    let args: Vec<_> = call
+        .arg_list()
+        .map(|arg_list| arg_list.args().collect())
+        .unwrap_or_default();
+
+    if params.len() != args.len() {
+        return Err(EvalError::ArityMismatch {
+            expected: params.len(),
+            found: args.len(),
+            range,
+        }
+        .into());
+    }
+
+    if env.depth() >= MAX_CALL_DEPTH {
+        return Err(EvalError::StackOverflow(range).into());
+    }
+
+    let mut frame = env.call_frame();
+
+    for (param, arg) in params.iter().zip(&args) {
+        let value = eval_expr(arg, env)?;
+        let name = param
+            .name()
+            .ok_or(EvalError::Missing(param.syntax().text_range()))?;
+        frame.define(name.text(), value);
+    }
+
+    let body = function
+        .body()
+        .ok_or(EvalError::Missing(function.syntax().text_range()))?;
+    Ok(finish_call(eval_block(&body, &mut frame))?)
+}
+
+fn eval_if(if_expr: &IfExpr, env: &mut Env) -> Result<Value, Unwind> {
+    let range = if_expr.syntax().text_range();
+
+    if !eval_condition(if_expr.syntax(), if_expr.condition(), env)? {
+        return match if_expr.else_branch() {
+            Some(node) => eval_node(&node, env),
+            None => Ok(Value::Unit),
+        };
+    }
+
+    let then_branch = if_expr.then_branch().ok_or(EvalError::Missing(range))?;
+    let value = eval_block(&then_branch, env)?;
+
+    // Without an `else` branch there is no value when the condition is false.
+    if if_expr.else_branch().is_none() {
+        return Ok(Value::Unit);
+    }
+
+    Ok(value)
+}
+
+fn eval_while(while_expr: &WhileExpr, env: &mut Env) -> Result<Value, Unwind> {
+    let range = while_expr.syntax().text_range();
+    let body = while_expr.body().ok_or(EvalError::Missing(range))?;
+
+    while eval_condition(while_expr.syntax(), while_expr.condition(), env)? {
+        match eval_block(&body, env) {
+            Ok(_) | Err(Unwind::Continue(_)) => {}
+            Err(Unwind::Break(_)) => break,
+            Err(unwind) => return Err(unwind),
+        }
+    }
+
+    Ok(Value::Unit)
+}
+
+/// Joins the fragments of the string with the values between them, which are shown
+/// the same way as they would be in the REPL.
+fn eval_interp_string(interp: &InterpString, env: &mut Env) -> Result<Value, Unwind> {
+    let parts: Vec<_> = interp.parts().collect();
+    let mut result = String::new();
+
+    for (index, part) in parts.iter().enumerate() {
+        match part {
+            InterpPart::Fragment(token) => {
+                let range = token.text_range();
+                let mut text = token.text();
+                if index == 0 {
+                    text = &text[1..];
+                }
+                if index == parts.len() - 1 {
+                    text = text
+                        .strip_suffix(['"', '\''])
+                        .ok_or(EvalError::Missing(range))?;
+                }
+                result.push_str(&unescape_contents(text).ok_or(EvalError::InvalidLiteral(range))?);
+            }
+            InterpPart::Expr(expr) => result.push_str(&eval_expr(expr, env)?.to_string()),
+        }
+    }
+
+    Ok(Value::Str(result))
+}
+
+fn eval_return(return_expr: &ReturnExpr, env: &mut Env) -> Result<Value, Unwind> {
+    let value = match return_expr.expr() {
+        Some(expr) => eval_expr(&expr, env)?,
+        None => Value::Unit,
+    };
+    Err(Unwind::Return(value))
+}
+
+/// Leaves the innermost loop. Loops don't have a value, so one given to `break` is
+/// only evaluated for its side effects.
+fn eval_break(break_expr: &BreakExpr, env: &mut Env) -> Result<Value, Unwind> {
+    if let Some(expr) = break_expr.expr() {
+        eval_expr(&expr, env)?;
+    }
+    Err(Unwind::Break(break_expr.syntax().text_range()))
+}
+
+fn eval_condition(
+    parent: &SyntaxNode,
+    condition: Option<Expr>,
+    env: &mut Env,
+) -> Result<bool, Unwind> {
+    let range = condition.as_ref().map_or(parent.text_range(), |condition| {
+        condition.syntax().text_range()
+    });
+
+    match eval_operand(parent, condition, env)? {
+        Value::Bool(value) => Ok(value),
+        value => Err(EvalError::TypeMismatch {
+            message: format!(
+                "expected condition of type Bool, found {}",
+                value.type_name()
+            ),
+            range,
+        }
+        .into()),
+    }
+}
+
+fn eval_let(let_stmt: &LetStmt, env: &mut Env) -> Result<(), Unwind> {
+    let range = let_stmt.syntax().text_range();
+    let name = let_stmt.name().ok_or(EvalError::Missing(range))?;
+
+    // Variables have to be initialized, since there is no way to assign them later.
+    let initializer = let_stmt
+        .initializer()
+        .ok_or(EvalError::Unsupported(range))?;
+    let value = eval_expr(&initializer, env)?;
+
+    env.define(name.text(), value);
+    Ok(())
+}
+
+fn eval_name_ref(name_ref: &NameRef, env: &Env) -> Result<Value, EvalError> {
+    let range = name_ref.syntax().text_range();
+    let name = name_ref.name().ok_or(EvalError::Missing(range))?;
+
+    env.lookup(name.text())
+        .cloned()
+        .ok_or_else(|| EvalError::UnboundName {
+            name: name.text().to_string(),
+            range: name.text_range(),
+        })
+}
+
+fn eval_literal(literal: &Literal) -> Result<Value, EvalError> {
+    let range = literal.syntax().text_range();
+    let (Some(token), Some(kind)) = (literal.token(), literal.kind()) else {
+        return Err(EvalError::Missing(range));
+    };
+    let text = token.text();
+
+    let value = match kind {
+        LiteralKind::Int => Value::Int(parse_int(text).ok_or(EvalError::InvalidLiteral(range))?),
+        LiteralKind::Float => {
+            Value::Float(text.parse().map_err(|_| EvalError::InvalidLiteral(range))?)
+        }
+        LiteralKind::Bool => Value::Bool(token.kind() == SyntaxKind::True),
+        LiteralKind::String if text.starts_with("\"\"\"") => {
+            return Err(EvalError::Unsupported(range))
+        }
+        LiteralKind::String => Value::Str(unescape(text).ok_or(EvalError::InvalidLiteral(range))?),
+        LiteralKind::Char | LiteralKind::ByteString | LiteralKind::Byte => {
+            return Err(EvalError::Unsupported(range))
+        }
+    };
+
+    Ok(value)
+}
+
+fn parse_int(text: &str) -> Option<i64> {
+    let (digits, radix) = match text.get(..2) {
+        Some("0x") => (&text[2..], 16),
+        Some("0o") => (&text[2..], 8),
+        Some("0b") => (&text[2..], 2),
+        _ => (text, 10),
+    };
+    i64::from_str_radix(digits, radix).ok()
+}
+
+/// Removes the quotes from a string literal, which can be double or single quotes,
+/// and resolves its escape sequences.
+fn unescape(text: &str) -> Option<String> {
+    let quote = text.chars().next().filter(|c| matches!(c, '"' | '\''))?;
+    unescape_contents(text.strip_prefix(quote)?.strip_suffix(quote)?)
+}
+
+fn unescape_contents(inner: &str) -> Option<String> {
+    let mut result = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        result.push(match chars.next()? {
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            '0' => '\0',
+            c @ ('\\' | '\'' | '"' | '$') => c,
+            _ => return None,
+        });
+    }
+
+    Some(result)
+}
+
+fn eval_prefix(prefix: &PrefixExpr, env: &mut Env) -> Result<Value, Unwind> {
+    let range = prefix.syntax().text_range();
+    let op = prefix.op().ok_or(EvalError::Missing(range))?;
+    let value = eval_operand(prefix.syntax(), prefix.expr(), env)?;
+
+    match (op.kind(), value) {
+        (SyntaxKind::Minus, Value::Int(value)) => value
+            .checked_neg()
+            .map(Value::Int)
+            .ok_or(EvalError::Overflow(range).into()),
+        (SyntaxKind::Minus, Value::Float(value)) => Ok(Value::Float(-value)),
+        (SyntaxKind::Not, Value::Bool(value)) => Ok(Value::Bool(!value)),
+        (SyntaxKind::Not, Value::Int(value)) => Ok(Value::Int(!value)),
+        (_, value) => Err(EvalError::TypeMismatch {
+            message: format!("cannot apply `{}` to {}", op.text(), value.type_name()),
+            range,
+        }
+        .into()),
+    }
+}
+
+fn eval_binary(binary: &BinaryExpr, env: &mut Env) -> Result<Value, Unwind> {
+    let range = binary.syntax().text_range();
+    let op = binary.op().ok_or(EvalError::Missing(range))?;
+    let lhs = eval_operand(binary.syntax(), binary.lhs(), env)?;
+
+    // The logical operators only evaluate their right hand side when needed.
+    match (op.kind(), &lhs) {
+        (SyntaxKind::AndAnd, Value::Bool(false)) => return Ok(Value::Bool(false)),
+        (SyntaxKind::OrOr, Value::Bool(true)) => return Ok(Value::Bool(true)),
+        _ => {}
+    }
+
+    let rhs = eval_operand(binary.syntax(), binary.rhs(), env)?;
+
+    let result = match (lhs, rhs) {
+        (Value::Int(lhs), Value::Int(rhs)) => int_op(&op, lhs, rhs, range),
+        (Value::Float(lhs), Value::Float(rhs)) => float_op(&op, lhs, rhs, range),
+        (Value::Bool(lhs), Value::Bool(rhs)) => bool_op(&op, lhs, rhs, range),
+        (Value::Str(lhs), Value::Str(rhs)) => str_op(&op, lhs, rhs, range),
+        (lhs, rhs) => Err(mismatch(&op, &lhs, &rhs, range)),
+    };
+    Ok(result?)
+}
+
+fn int_op(op: &SyntaxToken, lhs: i64, rhs: i64, range: TextRange) -> Result<Value, EvalError> {
+    let result = match op.kind() {
+        SyntaxKind::Plus => lhs.checked_add(rhs),
+        SyntaxKind::Minus => lhs.checked_sub(rhs),
+        SyntaxKind::Star => lhs.checked_mul(rhs),
+        SyntaxKind::Slash | SyntaxKind::Percent if rhs == 0 => {
+            return Err(EvalError::DivisionByZero(range))
+        }
+        SyntaxKind::Slash => lhs.checked_div(rhs),
+        SyntaxKind::Percent => lhs.checked_rem(rhs),
+        SyntaxKind::Amp => Some(lhs & rhs),
+        SyntaxKind::Pipe => Some(lhs | rhs),
+        SyntaxKind::Caret => Some(lhs ^ rhs),
+        _ => return compare(op, lhs.cmp(&rhs), range, "Int"),
+    };
+    result.map(Value::Int).ok_or(EvalError::Overflow(range))
+}
+
+fn float_op(op: &SyntaxToken, lhs: f64, rhs: f64, range: TextRange) -> Result<Value, EvalError> {
+    let result = match op.kind() {
+        SyntaxKind::Plus => lhs + rhs,
+        SyntaxKind::Minus => lhs - rhs,
+        SyntaxKind::Star => lhs * rhs,
+        SyntaxKind::Slash => lhs / rhs,
+        SyntaxKind::Percent => lhs % rhs,
+        kind => {
+            let value = match kind {
+                SyntaxKind::EqualEquals => lhs == rhs,
+                SyntaxKind::NotEquals => lhs != rhs,
+                SyntaxKind::LessThan => lhs < rhs,
+                SyntaxKind::GreaterThan => lhs > rhs,
+                SyntaxKind::LessThanEquals => lhs <= rhs,
+                SyntaxKind::GreaterThanEquals => lhs >= rhs,
+                _ => return Err(unsupported_op(op, "Float", range)),
+            };
+            return Ok(Value::Bool(value));
+        }
+    };
+    Ok(Value::Float(result))
+}
+
+fn bool_op(op: &SyntaxToken, lhs: bool, rhs: bool, range: TextRange) -> Result<Value, EvalError> {
+    let result = match op.kind() {
+        SyntaxKind::AndAnd | SyntaxKind::Amp => lhs & rhs,
+        SyntaxKind::OrOr | SyntaxKind::Pipe => lhs | rhs,
+        SyntaxKind::Caret => lhs ^ rhs,
+        SyntaxKind::EqualEquals => lhs == rhs,
+        SyntaxKind::NotEquals => lhs != rhs,
+        _ => return Err(unsupported_op(op, "Bool", range)),
+    };
+    Ok(Value::Bool(result))
+}
+
+fn str_op(
+    op: &SyntaxToken,
+    lhs: String,
+    rhs: String,
+    range: TextRange,
+) -> Result<Value, EvalError> {
+    match op.kind() {
+        SyntaxKind::Plus => Ok(Value::Str(lhs + &rhs)),
+        _ => compare(op, lhs.cmp(&rhs), range, "Str"),
+    }
+}
+
+fn compare(
+    op: &SyntaxToken,
+    ordering: std::cmp::Ordering,
+    range: TextRange,
+    type_name: &str,
+) -> Result<Value, EvalError> {
+    let result = match op.kind() {
+        SyntaxKind::EqualEquals => ordering.is_eq(),
+        SyntaxKind::NotEquals => ordering.is_ne(),
+        SyntaxKind::LessThan => ordering.is_lt(),
+        SyntaxKind::GreaterThan => ordering.is_gt(),
+        SyntaxKind::LessThanEquals => ordering.is_le(),
+        SyntaxKind::GreaterThanEquals => ordering.is_ge(),
+        _ => return Err(unsupported_op(op, type_name, range)),
+    };
+    Ok(Value::Bool(result))
+}
+
+fn unsupported_op(op: &SyntaxToken, type_name: &str, range: TextRange) -> EvalError {
+    EvalError::TypeMismatch {
+        message: format!("cannot apply `{}` to {type_name}", op.text()),
+        range,
+    }
+}
+
+fn mismatch(op: &SyntaxToken, lhs: &Value, rhs: &Value, range: TextRange) -> EvalError {
+    EvalError::TypeMismatch {
+        message: format!(
+            "cannot apply `{}` to {} and {}",
+            op.text(),
+            lhs.type_name(),
+            rhs.type_name()
+        ),
+        range,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rue_parser::parse_text;
+
+    /// Evaluates the body of `main`.
+    fn eval_str(body: &str) -> Result<Value, EvalError> {
+        let program = Program::cast(parse_text(&format!("fn main() {{ {body} }}"))).unwrap();
+        let main = program.functions().next().unwrap();
+        eval(main.body().unwrap().syntax())
+    }
+
+    #[test]
+    fn eval_precedence() {
+        assert_eq!(eval_str("1 + 2 * 3"), Ok(Value::Int(7)));
+        assert_eq!(eval_str("(1 + 2) * 3"), Ok(Value::Int(9)));
+    }
+
+    #[test]
+    fn eval_literals() {
+        assert_eq!(eval_str("0xFF"), Ok(Value::Int(255)));
+        assert_eq!(eval_str("2.5"), Ok(Value::Float(2.5)));
+        assert_eq!(eval_str("true"), Ok(Value::Bool(true)));
+        assert_eq!(eval_str(r#""a\tb""#), Ok(Value::Str("a\tb".to_string())));
+        assert_eq!(eval_str(r#"'a\'b'"#), Ok(Value::Str("a'b".to_string())));
+    }
+
+    #[test]
+    fn eval_operators() {
+        assert_eq!(eval_str("-(7 % 4)"), Ok(Value::Int(-3)));
+        assert_eq!(eval_str("1.5 * 2.0"), Ok(Value::Float(3.0)));
+        assert_eq!(eval_str("!(1 < 2) || 3 >= 3"), Ok(Value::Bool(true)));
+        assert_eq!(eval_str(r#""a" + "b" == "ab""#), Ok(Value::Bool(true)));
+        assert_eq!(eval_str("false && 1"), Ok(Value::Bool(false)));
+    }
+
+    #[test]
+    fn eval_type_mismatch() {
+        let error = eval_str("1 + true").unwrap_err();
+        assert!(matches!(error, EvalError::TypeMismatch { .. }));
+        assert_eq!(error.to_string(), "cannot apply `+` to Int and Bool");
+        assert_eq!(error.range(), TextRange::new(12.into(), 21.into()));
+    }
+
+    #[test]
+    fn eval_arithmetic_errors() {
+        assert!(matches!(
+            eval_str("1 / 0"),
+            Err(EvalError::DivisionByZero(_))
+        ));
+        assert!(matches!(
+            eval_str("9223372036854775807 + 1"),
+            Err(EvalError::Overflow(_))
+        ));
+    }
+
+    #[test]
+    fn eval_let() {
+        assert_eq!(eval_str("let x = 2; x * x"), Ok(Value::Int(4)));
+        assert_eq!(eval_str("let x = 1; let y = x + 1; y"), Ok(Value::Int(2)));
+    }
+
+    #[test]
+    fn eval_shadowing() {
+        assert_eq!(eval_str("let x = 1; let x = 2; x"), Ok(Value::Int(2)));
+        assert_eq!(eval_str("let x = 1; let x = x + 1; x"), Ok(Value::Int(2)));
+    }
+
+    #[test]
+    fn eval_block_without_tail() {
+        assert_eq!(eval_str(""), Ok(Value::Unit));
+        assert_eq!(eval_str("let x = 1;"), Ok(Value::Unit));
+        assert_eq!(eval_str("1 + 2;"), Ok(Value::Unit));
+    }
+
+    #[test]
+    fn eval_unbound_name() {
+        let error = eval_str("let x = 1; x + y").unwrap_err();
+        assert_eq!(
+            error,
+            EvalError::UnboundName {
+                name: "y".to_string(),
+                range: TextRange::new(27.into(), 28.into()),
+            }
+        );
+        assert_eq!(error.to_string(), "cannot find `y` in this scope");
+    }
+
+    fn run_str(source: &str) -> Result<Value, EvalError> {
+        run(&parse_text(source))
+    }
+
+    #[test]
+    fn eval_if() {
+        assert_eq!(eval_str("if 1 < 2 { 1 } else { 2 }"), Ok(Value::Int(1)));
+        assert_eq!(
+            eval_str("if false { 1 } else if false { 2 } else { 3 }"),
+            Ok(Value::Int(3))
+        );
+        assert_eq!(eval_str("if false { 1 }"), Ok(Value::Unit));
+        assert!(matches!(
+            eval_str("if 1 { 1 }"),
+            Err(EvalError::TypeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn run_call() {
+        let source = "fn add(a: Int, b: Int) -> Int { a + b }\nfn main() { add(1, 2) * 2 }";
+        assert_eq!(run_str(source), Ok(Value::Int(6)));
+    }
+
+    #[test]
+    fn run_recursion() {
+        let source = "
+            fn factorial(n: Int) -> Int {
+                if n <= 1 { 1 } else { n * factorial(n - 1) }
+            }
+
+            fn main() { factorial(10) }
+        ";
+        assert_eq!(run_str(source), Ok(Value::Int(3628800)));
+    }
+
+    /// Runs the source on a thread with a larger stack, since in debug builds each
+    /// nested call takes several kilobytes of it.
+    fn run_deep(source: String) -> Result<Value, EvalError> {
+        std::thread::Builder::new()
+            .stack_size(64 * 1024 * 1024)
+            .spawn(move || run_str(&source))
+            .unwrap()
+            .join()
+            .unwrap()
+    }
+
+    #[test]
+    fn run_infinite_recursion() {
+        let error = run_deep("fn main() { main() }".to_string()).unwrap_err();
+        assert_eq!(
+            error,
+            EvalError::StackOverflow(TextRange::new(12.into(), 19.into()))
+        );
+        assert_eq!(error.to_string(), "too many nested calls");
+
+        let source = "fn f(n: Int) -> Int { if n == 0 { 0 } else { 1 + f(n - 1) } }";
+        assert_eq!(
+            run_deep(format!("{source}\nfn main() -> Int {{ f(998) }}")),
+            Ok(Value::Int(998))
+        );
+        assert!(matches!(
+            run_deep(format!("{source}\nfn main() -> Int {{ f(999) }}")),
+            Err(EvalError::StackOverflow(_))
+        ));
+    }
+
+    #[test]
+    fn run_call_scope() {
+        let source = "fn get() -> Int { x }\nfn main() { let x = 1; get() }";
+        assert!(matches!(
+            run_str(source),
+            Err(EvalError::UnboundName { .. })
+        ));
+    }
+
+    #[test]
+    fn run_arity_mismatch() {
+        let source = "fn add(a: Int, b: Int) -> Int { a + b }\nfn main() { add(1) }";
+        let error = run_str(source).unwrap_err();
+        assert_eq!(
+            error,
+            EvalError::ArityMismatch {
+                expected: 2,
+                found: 1,
+                range: TextRange::new(52.into(), 59.into()),
+            }
+        );
+        assert_eq!(error.to_string(), "expected 2 arguments, found 1");
+    }
+
+    #[test]
+    fn run_return() {
+        let source = "fn f() -> Int { return 1; 2 }\nfn main() -> Int { f() + 1 }";
+        assert_eq!(run_str(source), Ok(Value::Int(2)));
+        assert_eq!(
+            run_str("fn main() -> Int { while true { return 3; } 4 }"),
+            Ok(Value::Int(3))
+        );
+        assert_eq!(run_str("fn main() { return; }"), Ok(Value::Unit));
+    }
+
+    #[test]
+    fn eval_break_continue() {
+        assert_eq!(
+            eval_str("while true { if false { continue; } break; } 5"),
+            Ok(Value::Int(5))
+        );
+        assert_eq!(
+            eval_str("break;"),
+            Err(EvalError::OutsideLoop(TextRange::new(12.into(), 17.into())))
+        );
+        assert!(matches!(
+            run_str("fn f() { continue; }\nfn main() { while true { f(); } }"),
+            Err(EvalError::OutsideLoop(_))
+        ));
+    }
+
+    #[test]
+    fn eval_interp_string() {
+        assert_eq!(
+            eval_str(r#""a ${1 + 2} b ${"c"} \$${2.0}""#),
+            Ok(Value::Str("a 3 b c $2.0".to_string()))
+        );
+        assert_eq!(
+            run_str("fn f() -> Str { \"a ${1} b\" }\nfn main() -> Str { f() }"),
+            Ok(Value::Str("a 1 b".to_string()))
+        );
+    }
+
+    #[test]
+    fn eval_field_access() {
+        assert_eq!(
+            run_str("fn f(a: Int) -> Int { a.b }\nfn main() -> Int { f(1) }"),
+            Err(EvalError::Unsupported(TextRange::new(22.into(), 26.into())))
+        );
+        assert!(matches!(
+            eval_str("1.abs()"),
+            Err(EvalError::Unsupported(_))
+        ));
+    }
+}
@@ -0,0 +1,61 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    Str(String),
+    Unit,
+}
+
+impl Value {
+    /// The name of the value's type, as it would be written in source.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Self::Int(_) => "Int",
+            Self::Float(_) => "Float",
+            Self::Bool(_) => "Bool",
+            Self::Str(_) => "Str",
+            Self::Unit => "Unit",
+        }
+    }
+}
+
+/// Renders the value the way a REPL would show it. Strings aren't quoted, and floats
+/// always have a fractional part, so that `3.0` can be told apart from `3`.
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(value) => write!(f, "{value}"),
+            Self::Float(value) => write!(f, "{value:?}"),
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Str(value) => write!(f, "{value}"),
+            Self::Unit => write!(f, "()"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_values() {
+        assert_eq!(Value::Int(3).to_string(), "3");
+        assert_eq!(Value::Int(-42).to_string(), "-42");
+        assert_eq!(Value::Float(3.0).to_string(), "3.0");
+        assert_eq!(Value::Float(0.5).to_string(), "0.5");
+        assert_eq!(Value::Bool(true).to_string(), "true");
+        assert_eq!(Value::Str("a \"b\"".to_string()).to_string(), "a \"b\"");
+        assert_eq!(Value::Unit.to_string(), "()");
+    }
+
+    #[test]
+    fn type_names() {
+        assert_eq!(Value::Int(3).type_name(), "Int");
+        assert_eq!(Value::Float(3.0).type_name(), "Float");
+        assert_eq!(Value::Str(String::new()).type_name(), "Str");
+        assert_eq!(Value::Unit.type_name(), "Unit");
+    }
+}
@@ -0,0 +1,28 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FmtConfig {
+    /// The number of spaces per indentation level, unless tabs are used.
+    pub indent: usize,
+    pub use_tabs: bool,
+    /// Longer runs of blank lines are collapsed to this many.
+    pub max_blank_lines: usize,
+}
+
+impl FmtConfig {
+    pub(crate) fn indent_unit(&self) -> String {
+        if self.use_tabs {
+            "\t".to_string()
+        } else {
+            " ".repeat(self.indent)
+        }
+    }
+}
+
+impl Default for FmtConfig {
+    fn default() -> Self {
+        Self {
+            indent: 4,
+            use_tabs: false,
+            max_blank_lines: 1,
+        }
+    }
+}
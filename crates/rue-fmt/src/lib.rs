@@ -0,0 +1,904 @@
+use rowan::{TextRange, TextSize};
+use rue_syntax::{SyntaxElement, SyntaxKind, SyntaxNode, SyntaxToken};
+
+mod fmt_config;
+
+pub use fmt_config::*;
+
+/// Formats a syntax tree into canonical source text.
+///
+/// Comments between items, statements or list elements are moved onto their own line
+/// before the next one, and lists containing them are laid out one element per line.
+/// Other comments are kept in place. Anything containing a syntax error is emitted
+/// verbatim.
+pub fn format(node: &SyntaxNode) -> String {
+    format_with(node, FmtConfig::default())
+}
+
+pub fn format_with(node: &SyntaxNode, config: FmtConfig) -> String {
+    let mut formatter = Formatter::new(config);
+    formatter.node(node);
+    formatter.finish()
+}
+
+struct Formatter {
+    config: FmtConfig,
+    indent_unit: String,
+    output: String,
+    indent: usize,
+    pending: Vec<Pending>,
+    /// The end of the comments attached to the start of the current item or statement,
+    /// which have already been made pending.
+    leading_end: TextSize,
+    /// The end of the current item, statement or list element. Comments before it are
+    /// inside of it, so they are kept in place.
+    line_end: TextSize,
+}
+
+/// Trivia which has been seen, but not yet emitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Pending {
+    Comment(String),
+    BlankLines(usize),
+}
+
+impl Formatter {
+    fn new(config: FmtConfig) -> Self {
+        Self {
+            config,
+            indent_unit: config.indent_unit(),
+            output: String::new(),
+            indent: 0,
+            pending: Vec::new(),
+            leading_end: TextSize::default(),
+            line_end: TextSize::default(),
+        }
+    }
+
+    fn finish(mut self) -> String {
+        self.flush_pending(false);
+
+        let len = self.output.trim_end().len();
+        self.output.truncate(len);
+        if !self.output.is_empty() {
+            self.output.push('\n');
+        }
+        self.output
+    }
+
+    fn node(&mut self, node: &SyntaxNode) {
+        if node.kind() != SyntaxKind::Program && has_errors(node) {
+            self.verbatim(node);
+            return;
+        }
+
+        match node.kind() {
+            SyntaxKind::Program => self.program(node),
+            SyntaxKind::Block => self.block(node),
+            _ if is_vertical_list(node) => self.vertical_list(node),
+            _ => self.inline(node),
+        }
+    }
+
+    fn program(&mut self, node: &SyntaxNode) {
+        let mut is_first = true;
+
+        for element in node.children_with_tokens() {
+            match element {
+                SyntaxElement::Token(token) => self.token(&token),
+                SyntaxElement::Node(child) => {
+                    // Items are always separated by a blank line.
+                    if !is_first {
+                        match self.pending.first_mut() {
+                            Some(Pending::BlankLines(count)) => *count = (*count).max(1),
+                            _ => self.pending.insert(0, Pending::BlankLines(1)),
+                        }
+                    }
+                    self.leading_trivia(&child);
+                    self.start_line(is_first);
+                    self.line_end = significant_end(&child);
+                    self.node(&child);
+                    is_first = false;
+                }
+            }
+        }
+    }
+
+    fn block(&mut self, node: &SyntaxNode) {
+        let line_end = self.line_end;
+        self.indent += 1;
+
+        let mut is_empty = true;
+        let mut is_closed = false;
+
+        for element in node.children_with_tokens() {
+            match element {
+                SyntaxElement::Token(token) => match token.kind() {
+                    SyntaxKind::OpenBrace => self.output.push('{'),
+                    SyntaxKind::CloseBrace => {
+                        if self.flush_pending(is_empty) {
+                            is_empty = false;
+                        }
+                        self.indent -= 1;
+                        if !is_empty {
+                            self.newline();
+                        }
+                        self.output.push('}');
+                        is_closed = true;
+                    }
+                    _ => self.token(&token),
+                },
+                SyntaxElement::Node(child) => {
+                    self.leading_trivia(&child);
+                    self.start_line(is_empty);
+                    self.line_end = significant_end(&child);
+                    self.node(&child);
+                    is_empty = false;
+                }
+            }
+        }
+
+        if !is_closed {
+            self.indent -= 1;
+        }
+        self.line_end = line_end;
+    }
+
+    /// Lays out a list one element per line, so that comments can be put on their own
+    /// line before the element after them.
+    fn vertical_list(&mut self, node: &SyntaxNode) {
+        let line_end = self.line_end;
+        let mut is_empty = true;
+
+        for element in node.children_with_tokens() {
+            match element {
+                SyntaxElement::Token(token) => match token.kind() {
+                    SyntaxKind::OpenParen | SyntaxKind::OpenBrace => {
+                        self.output.push_str(token.text());
+                        self.indent += 1;
+                    }
+                    SyntaxKind::CloseParen | SyntaxKind::CloseBrace => {
+                        self.flush_pending(is_empty);
+                        self.indent -= 1;
+                        self.newline();
+                        self.output.push_str(token.text());
+                        self.line_end = line_end;
+                    }
+                    SyntaxKind::Comma => {}
+                    _ if self.is_inside_line(&token) => self.comment(&token),
+                    _ => self.token(&token),
+                },
+                SyntaxElement::Node(child) => {
+                    if !is_empty {
+                        self.output.push(',');
+                    }
+                    self.leading_trivia(&child);
+                    self.start_line(is_empty);
+                    self.line_end = significant_end(&child);
+                    self.node(&child);
+                    is_empty = false;
+                }
+            }
+        }
+    }
+
+    fn inline(&mut self, node: &SyntaxNode) {
+        let mut previous = None;
+        let elements: Vec<SyntaxElement> = node.children_with_tokens().collect();
+
+        for (index, element) in elements.iter().enumerate() {
+            let kind = element.kind();
+
+            if let Some(token) = element.as_token().filter(|token| token.kind().is_trivia()) {
+                if self.is_inside_line(token) {
+                    if previous.is_some() {
+                        self.space();
+                    }
+                    self.comment(token);
+                    previous = Some(kind);
+                } else {
+                    self.trivia(token);
+                }
+                continue;
+            }
+
+            if kind == SyntaxKind::Comma && is_dropped_comma(node, &elements[index + 1..]) {
+                continue;
+            }
+
+            if let Some(previous) = previous {
+                if needs_space(node.kind(), previous, kind) {
+                    self.space();
+                }
+            }
+
+            match element {
+                SyntaxElement::Token(token) => self.output.push_str(token.text()),
+                SyntaxElement::Node(child) => self.node(child),
+            }
+
+            previous = Some(kind);
+        }
+    }
+
+    /// Whether the token is a comment within the current line, rather than between lines.
+    fn is_inside_line(&self, token: &SyntaxToken) -> bool {
+        let range = token.text_range();
+        matches!(
+            token.kind(),
+            SyntaxKind::LineComment | SyntaxKind::BlockComment
+        ) && range.end() > self.leading_end
+            && range.start() < self.line_end
+    }
+
+    /// Emits a comment in place. Since a line comment runs to the end of the line, the
+    /// rest of the line is continued on the next one.
+    fn comment(&mut self, token: &SyntaxToken) {
+        self.output.push_str(token.text().trim_end());
+
+        if token.kind() == SyntaxKind::LineComment {
+            self.indent += 1;
+            self.newline();
+            self.indent -= 1;
+        }
+    }
+
+    /// Separates two tokens, unless they are already on separate lines.
+    fn space(&mut self) {
+        if !self.output.ends_with(char::is_whitespace) {
+            self.output.push(' ');
+        }
+    }
+
+    fn token(&mut self, token: &SyntaxToken) {
+        if token.kind().is_trivia() {
+            self.trivia(token);
+        } else {
+            self.output.push_str(token.text());
+        }
+    }
+
+    /// Comments are attached to the start of the item or statement after them, so
+    /// they need to be emitted before its line starts.
+    fn leading_trivia(&mut self, node: &SyntaxNode) {
+        let tokens = node
+            .descendants_with_tokens()
+            .filter_map(|element| element.into_token())
+            .take_while(|token| token.kind().is_trivia());
+
+        for token in tokens {
+            self.trivia(&token);
+            self.leading_end = token.text_range().end();
+        }
+    }
+
+    fn trivia(&mut self, token: &SyntaxToken) {
+        if token.text_range().end() <= self.leading_end {
+            return;
+        }
+
+        match token.kind() {
+            SyntaxKind::Whitespace => {
+                let count = token.text().matches('\n').count().saturating_sub(1);
+                if count == 0 {
+                    return;
+                }
+
+                match self.pending.last_mut() {
+                    Some(Pending::BlankLines(previous)) => *previous = (*previous).max(count),
+                    _ => self.pending.push(Pending::BlankLines(count)),
+                }
+            }
+            SyntaxKind::LineComment | SyntaxKind::Shebang => {
+                let text = token.text().trim_end().to_string();
+                self.pending.push(Pending::Comment(text));
+            }
+            SyntaxKind::BlockComment => {
+                self.pending
+                    .push(Pending::Comment(token.text().to_string()));
+            }
+            _ => {}
+        }
+    }
+
+    fn verbatim(&mut self, node: &SyntaxNode) {
+        let range = node.text_range();
+        let start = self.leading_end.clamp(range.start(), range.end()) - range.start();
+        let text = node
+            .text()
+            .slice(TextRange::new(start, range.len()))
+            .to_string();
+        self.output.push_str(text.trim_end());
+    }
+
+    /// Starts the line for an item or statement, preceded by any pending comments.
+    fn start_line(&mut self, is_first: bool) {
+        let pending = self.take_pending(is_first);
+        self.emit_pending(pending);
+        self.newline();
+    }
+
+    /// Emits pending comments before the end of a block or the source, returning
+    /// whether there were any.
+    fn flush_pending(&mut self, is_first: bool) -> bool {
+        let mut pending = self.take_pending(is_first);
+        while matches!(pending.last(), Some(Pending::BlankLines(_))) {
+            pending.pop();
+        }
+
+        let has_comments = !pending.is_empty();
+        self.emit_pending(pending);
+        has_comments
+    }
+
+    /// Takes the pending trivia, dropping blank lines at the start of a block.
+    fn take_pending(&mut self, is_first: bool) -> Vec<Pending> {
+        let mut pending = std::mem::take(&mut self.pending);
+        if is_first && matches!(pending.first(), Some(Pending::BlankLines(_))) {
+            pending.remove(0);
+        }
+        pending
+    }
+
+    fn emit_pending(&mut self, pending: Vec<Pending>) {
+        for entry in pending {
+            match entry {
+                Pending::Comment(comment) => {
+                    self.newline();
+                    self.output.push_str(&comment);
+                }
+                Pending::BlankLines(_) if self.output.is_empty() => {}
+                Pending::BlankLines(count) => {
+                    for _ in 0..count.min(self.config.max_blank_lines) {
+                        self.output.push('\n');
+                    }
+                }
+            }
+        }
+    }
+
+    fn newline(&mut self) {
+        if self.output.is_empty() {
+            return;
+        }
+
+        self.output.push('\n');
+        for _ in 0..self.indent {
+            self.output.push_str(&self.indent_unit);
+        }
+    }
+}
+
+fn has_errors(node: &SyntaxNode) -> bool {
+    node.descendants_with_tokens().any(|element| {
+        matches!(
+            element.kind(),
+            SyntaxKind::ErrorToken | SyntaxKind::ErrorNode
+        )
+    })
+}
+
+/// Field lists are always laid out vertically unless they are empty, and other lists
+/// are when they contain comments between their elements.
+fn is_vertical_list(node: &SyntaxNode) -> bool {
+    if node.kind() == SyntaxKind::FieldList && node.children().next().is_some() {
+        return true;
+    }
+
+    if !matches!(
+        node.kind(),
+        SyntaxKind::ParamList
+            | SyntaxKind::ArgList
+            | SyntaxKind::FieldList
+            | SyntaxKind::VariantList
+    ) {
+        return false;
+    }
+
+    let elements: Vec<TextRange> = node
+        .children()
+        .filter_map(|child| significant_range(&child))
+        .collect();
+
+    node.descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter(|token| {
+            matches!(
+                token.kind(),
+                SyntaxKind::LineComment | SyntaxKind::BlockComment
+            )
+        })
+        .any(|token| {
+            !elements
+                .iter()
+                .any(|range| range.contains_range(token.text_range()))
+        })
+}
+
+/// The range from the first to the last token which isn't trivia.
+fn significant_range(node: &SyntaxNode) -> Option<TextRange> {
+    let mut tokens = node
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter(|token| !token.kind().is_trivia());
+
+    let first = tokens.next()?;
+    let last = tokens.last().unwrap_or_else(|| first.clone());
+    Some(TextRange::new(
+        first.text_range().start(),
+        last.text_range().end(),
+    ))
+}
+
+fn significant_end(node: &SyntaxNode) -> TextSize {
+    significant_range(node).map_or(node.text_range().start(), |range| range.end())
+}
+
+fn needs_space(parent: SyntaxKind, previous: SyntaxKind, next: SyntaxKind) -> bool {
+    match (previous, next) {
+        (
+            _,
+            SyntaxKind::Comma
+            | SyntaxKind::Dot
+            | SyntaxKind::Semicolon
+            | SyntaxKind::Colon
+            | SyntaxKind::ColonColon
+            | SyntaxKind::CloseParen
+            | SyntaxKind::ParamList
+            | SyntaxKind::ArgList
+            | SyntaxKind::TypeList
+            | SyntaxKind::GenericParamList
+            | SyntaxKind::GenericArgList,
+        ) => false,
+        (SyntaxKind::OpenParen | SyntaxKind::Dot | SyntaxKind::ColonColon, _) => false,
+        (SyntaxKind::OpenBrace, SyntaxKind::CloseBrace) => false,
+        (SyntaxKind::LessThan, _) | (_, SyntaxKind::GreaterThan)
+            if matches!(
+                parent,
+                SyntaxKind::GenericParamList | SyntaxKind::GenericArgList
+            ) =>
+        {
+            false
+        }
+        (SyntaxKind::Amp, _) if parent == SyntaxKind::RefType => false,
+        _ => !matches!(
+            parent,
+            SyntaxKind::PrefixExpr
+                | SyntaxKind::Attr
+                | SyntaxKind::Visibility
+                | SyntaxKind::InterpString
+        ),
+    }
+}
+
+/// Trailing commas are removed, except in one element tuples where they matter.
+fn is_dropped_comma(parent: &SyntaxNode, rest: &[SyntaxElement]) -> bool {
+    let next = rest
+        .iter()
+        .map(|element| element.kind())
+        .find(|kind| !kind.is_trivia());
+    if !matches!(
+        next,
+        Some(SyntaxKind::CloseParen | SyntaxKind::CloseBrace | SyntaxKind::GreaterThan)
+    ) {
+        return false;
+    }
+
+    match parent.kind() {
+        SyntaxKind::TupleType => parent.children().count() > 1,
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+    };
+
+    use arbitrary::{Arbitrary, Unstructured};
+    use expect_test::{expect, expect_file, Expect};
+    use rue_parser::{
+        arbitrary::{render, Program},
+        parse_text, parse_text_with_errors,
+    };
+    use rue_syntax::to_sexpr;
+
+    use super::*;
+
+    fn check(source: &str, expected: Expect) {
+        expected.assert_eq(&format(&parse_text(source)));
+    }
+
+    #[test]
+    fn format_empty_fn() {
+        check(
+            "fn   main(){}",
+            expect![[r#"
+                fn main() {}
+            "#]],
+        );
+    }
+
+    #[test]
+    fn format_signature() {
+        check(
+            "fn add ( a : Int , b:(Int,Int,) , )->Int{}",
+            expect![[r#"
+                fn add(a: Int, b: (Int, Int)) -> Int {}
+            "#]],
+        );
+    }
+
+    #[test]
+    fn format_block() {
+        check(
+            "fn main() { let x:Int=1+2*3;   \n\n\n  if x==7{x}else{ -x }\nwhile x { x ; }\nx }",
+            expect![[r#"
+                fn main() {
+                    let x: Int = 1 + 2 * 3;
+
+                    if x == 7 {
+                        x
+                    } else {
+                        -x
+                    }
+                    while x {
+                        x;
+                    }
+                    x
+                }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn format_comments() {
+        check(
+            "// The entrypoint.\nfn main() { let x = 1; // One.\n\n  /* Two. */ x }\n// Trailing.\n",
+            expect![[r#"
+                // The entrypoint.
+                fn main() {
+                    let x = 1;
+                    // One.
+
+                    /* Two. */
+                    x
+                }
+                // Trailing.
+            "#]],
+        );
+    }
+
+    #[test]
+    fn format_list_comments() {
+        check(
+            "struct Point {\n    // The horizontal position.\n    x: Int, y: Int }\nfn f(\n    // The input.\n    a: Int,\n) { g(a, /* b */ b) }",
+            expect![[r#"
+                struct Point {
+                    // The horizontal position.
+                    x: Int,
+                    y: Int
+                }
+
+                fn f(
+                    // The input.
+                    a: Int
+                ) {
+                    g(
+                        a,
+                        /* b */
+                        b
+                    )
+                }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn format_inline_comments() {
+        check(
+            "fn main() { 1 + /* c */ 2; let x = 1 + // split\n 2; x }",
+            expect![[r#"
+                fn main() {
+                    1 + /* c */ 2;
+                    let x = 1 + // split
+                        2;
+                    x
+                }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn format_items() {
+        check(
+            "fn a() {}\n\n\n\nfn b() {} fn c() {}",
+            expect![[r#"
+                fn a() {}
+
+                fn b() {}
+
+                fn c() {}
+            "#]],
+        );
+    }
+
+    #[test]
+    fn format_attrs() {
+        check(
+            "#! [ no_std ]\n# [ test ]  fn main() {}",
+            expect![[r#"
+                #![no_std]
+
+                #[test] fn main() {}
+            "#]],
+        );
+    }
+
+    #[test]
+    fn format_visibility() {
+        check(
+            "pub ( crate )  fn main() {}",
+            expect![[r#"
+                pub(crate) fn main() {}
+            "#]],
+        );
+    }
+
+    #[test]
+    fn format_struct() {
+        check(
+            "struct A{}\nstruct B ;\nstruct C{x:Int,y : Str}\nstruct D { x: Int, }",
+            expect![[r#"
+                struct A {}
+
+                struct B;
+
+                struct C {
+                    x: Int,
+                    y: Str
+                }
+
+                struct D {
+                    x: Int
+                }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn format_enum() {
+        check(
+            "enum E{A,B ( Int , Str )}",
+            expect![[r#"
+                enum E { A, B(Int, Str) }
+            "#]],
+        );
+        check(
+            "enum E { A, B, }",
+            expect![[r#"
+                enum E { A, B }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn format_generics() {
+        check(
+            "fn f < T > ( x : Vec < Vec < T > > ) {}",
+            expect![[r#"
+                fn f<T>(x: Vec<Vec<T>>) {}
+            "#]],
+        );
+        check(
+            "fn f<T,U,>(x: Map<T,U,>) {}",
+            expect![[r#"
+                fn f<T, U>(x: Map<T, U>) {}
+            "#]],
+        );
+    }
+
+    #[test]
+    fn format_ref_types() {
+        check(
+            "fn f(x: & mut Int, y: & &Int) {}",
+            expect![[r#"
+                fn f(x: &mut Int, y: &&Int) {}
+            "#]],
+        );
+    }
+
+    #[test]
+    fn format_interp_string() {
+        check(
+            r#"fn main() { "a ${ b  +  1 } c" }"#,
+            expect![[r#"
+                fn main() {
+                    "a ${b + 1} c"
+                }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn format_shebang() {
+        check(
+            "#!/usr/bin/env rue\n\n\nfn main() {}",
+            expect![[r#"
+                #!/usr/bin/env rue
+
+                fn main() {}
+            "#]],
+        );
+    }
+
+    #[test]
+    fn format_errors_verbatim() {
+        check(
+            "// Broken.\nfn main() { 1 2 }\nfn  ok() {}",
+            expect![[r#"
+                // Broken.
+                fn main() { 1 2 }
+
+                fn ok() {}
+            "#]],
+        );
+    }
+
+    #[test]
+    fn format_unclosed_block() {
+        check(
+            "fn main() { x",
+            expect![[r#"
+                fn main() {
+                    x
+            "#]],
+        );
+    }
+
+    fn corpus() -> Vec<PathBuf> {
+        let corpus = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/corpus");
+
+        let mut paths: Vec<_> = fs::read_dir(corpus)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        paths.sort();
+        assert!(paths.len() >= 10);
+        paths
+    }
+
+    #[test]
+    fn format_corpus() {
+        let expected = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/expected");
+
+        for path in corpus() {
+            let source = fs::read_to_string(&path).unwrap();
+            let formatted = format(&parse_text(&source));
+            expect_file![Path::new(expected).join(path.file_name().unwrap())].assert_eq(&formatted);
+        }
+    }
+
+    #[test]
+    fn format_is_idempotent() {
+        for path in corpus() {
+            let source = fs::read_to_string(&path).unwrap();
+            let once = format(&parse_text(&source));
+            let twice = format(&parse_text(&once));
+            assert_eq!(once, twice, "{} is not stable", path.display());
+        }
+    }
+
+    #[test]
+    fn format_generated_programs() {
+        let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+
+        for _ in 0..1000 {
+            let bytes: Vec<u8> = (0..512)
+                .map(|_| {
+                    seed ^= seed << 13;
+                    seed ^= seed >> 7;
+                    seed ^= seed << 17;
+                    seed as u8
+                })
+                .collect();
+
+            let program = Program::arbitrary_take_rest(Unstructured::new(&bytes)).unwrap();
+            let source = render(&program);
+
+            let parse = parse_text_with_errors(&source);
+            assert_eq!(parse.errors, [], "{source}");
+
+            let formatted = format(&parse.node);
+            let reparse = parse_text_with_errors(&formatted);
+            assert_eq!(reparse.errors, [], "{formatted}");
+            assert_eq!(to_sexpr(&parse.node), to_sexpr(&reparse.node));
+        }
+    }
+
+    fn check_with(source: &str, config: FmtConfig, expected: Expect) {
+        expected.assert_eq(&format_with(&parse_text(source), config));
+    }
+
+    const NESTED: &str = "fn main() { while a { if b { c } } }";
+
+    #[test]
+    fn format_two_space_indent() {
+        let config = FmtConfig {
+            indent: 2,
+            ..FmtConfig::default()
+        };
+        check_with(
+            NESTED,
+            config,
+            expect![[r#"
+                fn main() {
+                  while a {
+                    if b {
+                      c
+                    }
+                  }
+                }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn format_tab_indent() {
+        let config = FmtConfig {
+            use_tabs: true,
+            ..FmtConfig::default()
+        };
+        let output = format_with(&parse_text(NESTED), config);
+        assert_eq!(
+            output,
+            "fn main() {\n\twhile a {\n\t\tif b {\n\t\t\tc\n\t\t}\n\t}\n}\n"
+        );
+    }
+
+    #[test]
+    fn format_collapses_blank_lines() {
+        let source = "fn main() {\n    a;\n\n\n\n    b;\n\n    c\n}\n\n\n\nfn other() {}";
+
+        let config = FmtConfig {
+            max_blank_lines: 2,
+            ..FmtConfig::default()
+        };
+        check_with(
+            source,
+            config,
+            expect![[r#"
+                fn main() {
+                    a;
+
+
+                    b;
+
+                    c
+                }
+
+
+                fn other() {}
+            "#]],
+        );
+
+        let config = FmtConfig {
+            max_blank_lines: 0,
+            ..FmtConfig::default()
+        };
+        check_with(
+            source,
+            config,
+            expect![[r#"
+                fn main() {
+                    a;
+                    b;
+                    c
+                }
+                fn other() {}
+            "#]],
+        );
+    }
+}
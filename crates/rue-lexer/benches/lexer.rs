@@ -0,0 +1,32 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use rue_lexer::Lexer;
+
+fn lex_sample(c: &mut Criterion) {
+    let source = include_str!("../tests/data/sample.rue").repeat(200);
+
+    let mut group = c.benchmark_group("lexer");
+    group.throughput(Throughput::Bytes(source.len() as u64));
+    group.bench_function("sample", |b| {
+        b.iter(|| Lexer::new(black_box(&source)).count())
+    });
+    group.finish();
+}
+
+/// Identifiers and keywords, including ones which only differ from a keyword by a
+/// suffix, since those are the slowest to tell apart.
+fn lex_identifiers(c: &mut Criterion) {
+    let words = [
+        "fn", "fns", "let", "letter", "while", "whilst", "x", "value", "Int", "λ",
+    ];
+    let source = words.repeat(20_000).join(" ");
+
+    let mut group = c.benchmark_group("lexer");
+    group.throughput(Throughput::Bytes(source.len() as u64));
+    group.bench_function("identifiers", |b| {
+        b.iter(|| Lexer::new(black_box(&source)).count())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, lex_sample, lex_identifiers);
+criterion_main!(benches);
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use crate::TokenKind;
+
+/// The built-in keywords, which are otherwise lexed as identifiers.
+pub(crate) const KEYWORDS: &[&str] = &[
+    "fn", "let", "mut", "if", "else", "while", "for", "return", "break", "continue", "struct",
+    "enum", "pub", "crate", "super", "true", "false",
+];
+
+/// Looks up a built-in keyword. This is a match rather than a table lookup, since it's
+/// on the lexer's hot path.
+pub(crate) fn builtin_keyword(word: &str) -> Option<TokenKind> {
+    Some(match word {
+        "fn" => TokenKind::Fn,
+        "let" => TokenKind::Let,
+        "mut" => TokenKind::Mut,
+        "if" => TokenKind::If,
+        "else" => TokenKind::Else,
+        "while" => TokenKind::While,
+        "for" => TokenKind::For,
+        "return" => TokenKind::Return,
+        "break" => TokenKind::Break,
+        "continue" => TokenKind::Continue,
+        "struct" => TokenKind::Struct,
+        "enum" => TokenKind::Enum,
+        "pub" => TokenKind::Pub,
+        "crate" => TokenKind::Crate,
+        "super" => TokenKind::Super,
+        "true" => TokenKind::True,
+        "false" => TokenKind::False,
+        _ => return None,
+    })
+}
+
+/// Decides which words are keywords, so that the syntax can be experimented with,
+/// for example by spelling `fn` as `func`. The default is the built-in set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeywordTable {
+    keywords: HashMap<String, TokenKind>,
+}
+
+impl KeywordTable {
+    /// Makes the word lex as the keyword, in addition to any other spellings.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the kind isn't a keyword.
+    pub fn insert(&mut self, word: impl Into<String>, kind: TokenKind) {
+        assert!(kind.is_keyword(), "{kind:?} is not a keyword");
+        self.keywords.insert(word.into(), kind);
+    }
+
+    /// Makes the word lex as an identifier.
+    pub fn remove(&mut self, word: &str) {
+        self.keywords.remove(word);
+    }
+
+    pub fn get(&self, word: &str) -> Option<TokenKind> {
+        self.keywords.get(word).copied()
+    }
+}
+
+impl Default for KeywordTable {
+    fn default() -> Self {
+        Self {
+            keywords: KEYWORDS
+                .iter()
+                .filter_map(|&word| Some((word.to_string(), builtin_keyword(word)?)))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_table() {
+        let table = KeywordTable::default();
+        assert_eq!(table.keywords.len(), KEYWORDS.len());
+        for &word in KEYWORDS {
+            assert!(table.get(word).is_some_and(TokenKind::is_keyword), "{word}");
+        }
+        assert_eq!(table.get("func"), None);
+    }
+}
@@ -0,0 +1,16 @@
+use std::ops::Range;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LexError {
+    pub span: Range<usize>,
+    pub message: String,
+}
+
+impl LexError {
+    pub fn new(span: Range<usize>, message: impl Into<String>) -> Self {
+        Self {
+            span,
+            message: message.into(),
+        }
+    }
+}
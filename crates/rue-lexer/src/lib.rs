@@ -1,52 +1,322 @@
-use std::str::Chars;
+use std::ops::Range;
 
+use keyword_table::builtin_keyword;
+use unicode_xid::UnicodeXID;
+
+mod keyword_table;
+mod lex_error;
+mod line_index;
 mod token;
 mod token_kind;
 
+#[cfg(test)]
+mod test_support;
+
+pub use keyword_table::KeywordTable;
+pub use lex_error::*;
+pub use line_index::*;
 pub use token::*;
 pub use token_kind::*;
 
+/// Lexes the source and returns the token containing the byte offset, if any.
+pub fn token_at(source: &str, offset: usize) -> Option<Token<'_>> {
+    Lexer::new(source).find(|token| token.contains(offset))
+}
+
 pub struct Lexer<'a> {
     source: &'a str,
-    chars: Chars<'a>,
     pos: usize,
+    line_col: LineCol,
+    errors: Vec<LexError>,
+    mode: Mode,
+    interpolations: Vec<Interpolation>,
+    keywords: Option<&'a KeywordTable>,
+}
+
+/// What the next token will be, when it depends on more than the next character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Normal,
+    /// A string fragment was just lexed, and `${` comes next.
+    InterpStart {
+        quote: char,
+    },
+    /// An interpolation was just closed, and the rest of the string comes next.
+    StringRest {
+        quote: char,
+    },
+}
+
+/// An interpolation which hasn't been closed yet.
+#[derive(Debug, Clone, Copy)]
+struct Interpolation {
+    quote: char,
+    start: usize,
+    /// The number of braces opened inside of the interpolation, so that the
+    /// braces of a block don't end it early.
+    depth: usize,
+}
+
+/// How the contents of a string ended.
+enum StringEnd {
+    Quote,
+    Interpolation,
+    Unterminated,
 }
 
 impl<'a> Lexer<'a> {
     pub fn new(source: &'a str) -> Self {
-        let chars = source.chars();
         Self {
             source,
-            chars,
             pos: 0,
+            line_col: LineCol::default(),
+            errors: Vec::new(),
+            mode: Mode::Normal,
+            interpolations: Vec::new(),
+            keywords: None,
+        }
+    }
+
+    /// Lexes with a different set of keywords than the built-in one.
+    pub fn with_keywords(source: &'a str, keywords: &'a KeywordTable) -> Self {
+        Self {
+            keywords: Some(keywords),
+            ..Self::new(source)
+        }
+    }
+
+    /// Starts lexing partway through the source, so that only a changed region needs
+    /// to be lexed again. The offset must be the start of a token, outside of any
+    /// string interpolation, in which case the tokens are the same as the ones a full
+    /// lex produces from there.
+    pub fn new_at(source: &'a str, offset: usize) -> Self {
+        debug_assert!(
+            is_token_boundary(source, offset),
+            "{offset} is not a token boundary"
+        );
+
+        let before = &source[..offset];
+        let line = before.matches('\n').count() as u32;
+        let col = before
+            .rsplit('\n')
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .count() as u32;
+
+        Self {
+            pos: offset,
+            line_col: LineCol { line, col },
+            ..Self::new(source)
         }
     }
 
+    /// Skips whitespace and comments, yielding only the tokens which affect parsing.
+    pub fn significant(self) -> impl Iterator<Item = Token<'a>> {
+        self.filter(|token| !token.kind().is_trivia())
+    }
+
+    /// Takes the diagnostics reported for the tokens lexed so far.
+    pub fn take_diagnostics(&mut self) -> Vec<LexError> {
+        std::mem::take(&mut self.errors)
+    }
+
+    /// The line and column of the next character to be lexed.
+    pub fn line_col(&self) -> LineCol {
+        self.line_col
+    }
+
     fn next_token(&mut self) -> Token<'a> {
         let start = self.pos;
 
-        let kind = match self.bump() {
+        let kind = match std::mem::replace(&mut self.mode, Mode::Normal) {
+            Mode::Normal => self.normal_token(start),
+            Mode::InterpStart { quote } => {
+                self.bump();
+                self.bump();
+                self.interpolations.push(Interpolation {
+                    quote,
+                    start,
+                    depth: 0,
+                });
+                TokenKind::InterpStart
+            }
+            Mode::StringRest { quote } if self.peek() != '\n' => self.string_rest(quote, start),
+            Mode::StringRest { .. } => {
+                self.error(start..start, "unterminated string literal");
+                self.normal_token(start)
+            }
+        };
+
+        self.validate(kind, start);
+
+        Token::new(kind, &self.source[start..self.pos], start)
+    }
+
+    fn normal_token(&mut self, start: usize) -> TokenKind {
+        if start == 0 && self.at_shebang() {
+            return self.shebang();
+        }
+
+        match self.bump() {
             '(' => TokenKind::OpenParen,
             ')' => TokenKind::CloseParen,
-            '{' => TokenKind::OpenBrace,
-            '}' => TokenKind::CloseBrace,
+            '{' => {
+                if let Some(interpolation) = self.interpolations.last_mut() {
+                    interpolation.depth += 1;
+                }
+                TokenKind::OpenBrace
+            }
+            '}' => self.close_brace(),
+            '[' => TokenKind::OpenBracket,
+            ']' => TokenKind::CloseBracket,
+
+            '#' => TokenKind::Hash,
+            '.' => TokenKind::Dot,
+            ',' => TokenKind::Comma,
+            ':' => self.joined(':', TokenKind::ColonColon, TokenKind::Colon),
+            ';' => TokenKind::Semicolon,
 
-            '-' => TokenKind::Minus,
-            '>' => TokenKind::GreaterThan,
+            '=' => self.joined('=', TokenKind::EqualEquals, TokenKind::Equals),
+            '!' => self.joined('=', TokenKind::NotEquals, TokenKind::Not),
+            '<' => self.joined('=', TokenKind::LessThanEquals, TokenKind::LessThan),
+            '>' => self.joined('=', TokenKind::GreaterThanEquals, TokenKind::GreaterThan),
 
+            '+' => self.joined('=', TokenKind::PlusEquals, TokenKind::Plus),
+            '-' => match self.peek() {
+                '>' => {
+                    self.bump();
+                    TokenKind::Arrow
+                }
+                '=' => {
+                    self.bump();
+                    TokenKind::MinusEquals
+                }
+                _ => TokenKind::Minus,
+            },
+            '*' => self.joined('=', TokenKind::StarEquals, TokenKind::Star),
             '/' => match self.peek() {
                 '/' => self.line_comment(),
                 '*' => self.block_comment(),
-                _ => TokenKind::Unknown,
+                '=' => {
+                    self.bump();
+                    TokenKind::SlashEquals
+                }
+                _ => TokenKind::Slash,
             },
+            '%' => self.joined('=', TokenKind::PercentEquals, TokenKind::Percent),
+
+            '&' => self.joined('&', TokenKind::AndAnd, TokenKind::Amp),
+            '|' => self.joined('|', TokenKind::OrOr, TokenKind::Pipe),
+            '^' => TokenKind::Caret,
 
-            c @ ('\'' | '"') => self.string(c),
-            c if c.is_whitespace() => self.whitespace(),
-            c if is_id_start(c) => self.ident(c),
+            '\'' => self.char_literal(),
+            'b' if matches!(self.peek(), '"' | '\'') => self.byte_literal(start),
+            '"' if self.peek() == '"' && self.peek_nth(1) == '"' => self.multiline_string(),
+            '"' => self.string('"'),
+            c if c.is_whitespace() => self.whitespace(c),
+            c if is_id_start(c) => self.ident(start),
+            c if c.is_ascii_digit() => self.number(c),
             _ => TokenKind::Unknown,
+        }
+    }
+
+    /// A closing brace ends the innermost interpolation, unless it closes a brace
+    /// which was opened inside of it.
+    fn close_brace(&mut self) -> TokenKind {
+        match self.interpolations.last_mut() {
+            Some(interpolation) if interpolation.depth > 0 => {
+                interpolation.depth -= 1;
+                TokenKind::CloseBrace
+            }
+            Some(&mut Interpolation { quote, .. }) => {
+                self.interpolations.pop();
+                self.mode = Mode::StringRest { quote };
+                TokenKind::InterpEnd
+            }
+            None => TokenKind::CloseBrace,
+        }
+    }
+
+    /// Reports anything left open at the end of the source.
+    fn finish(&mut self) {
+        if let Mode::StringRest { .. } = self.mode {
+            self.mode = Mode::Normal;
+            self.error(self.pos..self.pos, "unterminated string literal");
+        }
+
+        for interpolation in std::mem::take(&mut self.interpolations) {
+            let span = interpolation.start..interpolation.start + 2;
+            self.error(span, "unterminated interpolation");
+        }
+    }
+
+    fn validate(&mut self, kind: TokenKind, start: usize) {
+        let message = match kind {
+            TokenKind::Unknown => "unknown character",
+            TokenKind::BlockComment {
+                is_terminated: false,
+            } => "unterminated block comment",
+            TokenKind::String {
+                is_terminated: false,
+                ..
+            } => "unterminated string literal",
+            TokenKind::Char {
+                is_terminated: false,
+            } => "unterminated character literal",
+            TokenKind::MultilineString {
+                is_terminated: false,
+                ..
+            } => "unterminated string literal",
+            TokenKind::ByteString {
+                is_terminated: false,
+                ..
+            } => "unterminated byte string literal",
+            TokenKind::Byte {
+                is_terminated: false,
+                ..
+            } => "unterminated byte literal",
+            TokenKind::ByteString {
+                has_non_ascii: true,
+                ..
+            }
+            | TokenKind::Byte {
+                has_non_ascii: true,
+                ..
+            } => "non-ASCII character in byte literal",
+            TokenKind::Int { is_empty: true, .. } => "missing digits after integer base prefix",
+            _ => return,
+        };
+        self.error(start..self.pos, message);
+    }
+
+    fn error(&mut self, span: Range<usize>, message: &str) {
+        self.errors.push(LexError::new(span, message));
+    }
+
+    fn joined(&mut self, next: char, joined: TokenKind, single: TokenKind) -> TokenKind {
+        if self.peek() == next {
+            self.bump();
+            joined
+        } else {
+            single
+        }
+    }
+
+    /// A shebang starts with `#!`, but an inner attribute such as `#![name]` on the
+    /// first line isn't one.
+    fn at_shebang(&self) -> bool {
+        let Some(rest) = self.source.strip_prefix("#!") else {
+            return false;
         };
+        !rest.trim_start().starts_with('[')
+    }
 
-        Token::new(kind, &self.source[start..self.pos])
+    fn shebang(&mut self) -> TokenKind {
+        while !matches!(self.peek(), '\0' | '\n') {
+            self.bump();
+        }
+        TokenKind::Shebang
     }
 
     fn line_comment(&mut self) -> TokenKind {
@@ -59,6 +329,7 @@ impl<'a> Lexer<'a> {
 
     fn block_comment(&mut self) -> TokenKind {
         self.bump();
+        let mut depth = 1;
         loop {
             match self.bump() {
                 '\0' => {
@@ -66,76 +337,324 @@ impl<'a> Lexer<'a> {
                         is_terminated: false,
                     }
                 }
+                '/' if self.peek() == '*' => {
+                    self.bump();
+                    depth += 1;
+                }
                 '*' if self.peek() == '/' => {
                     self.bump();
-                    return TokenKind::BlockComment {
-                        is_terminated: true,
-                    };
+                    depth -= 1;
+                    if depth == 0 {
+                        return TokenKind::BlockComment {
+                            is_terminated: true,
+                        };
+                    }
                 }
                 _ => {}
             }
         }
     }
 
+    fn char_literal(&mut self) -> TokenKind {
+        if !self.at_char_literal() {
+            return self.string('\'');
+        }
+
+        let is_terminated = self.char_contents();
+        TokenKind::Char { is_terminated }
+    }
+
+    fn char_contents(&mut self) -> bool {
+        if self.bump() == '\\' {
+            self.escape();
+        }
+
+        let is_terminated = self.peek() == '\'';
+        if is_terminated {
+            self.bump();
+        }
+        is_terminated
+    }
+
+    /// Lexes `b'x'` or `b"abc"`, which are scanned like characters and strings, but
+    /// can't be interpolated and may only contain ASCII.
+    fn byte_literal(&mut self, start: usize) -> TokenKind {
+        let quote = self.bump();
+
+        if quote == '\'' && self.at_char_literal() {
+            let is_terminated = self.char_contents();
+            return TokenKind::Byte {
+                is_terminated,
+                has_non_ascii: !self.source[start..self.pos].is_ascii(),
+            };
+        }
+
+        let (end, has_invalid_escape) = self.string_contents(quote, false);
+        TokenKind::ByteString {
+            is_terminated: matches!(end, StringEnd::Quote),
+            has_invalid_escape,
+            has_non_ascii: !self.source[start..self.pos].is_ascii(),
+        }
+    }
+
+    fn at_char_literal(&self) -> bool {
+        let len = match self.peek() {
+            '\\' => 2,
+            '\'' | '\n' | '\0' => return false,
+            _ => 1,
+        };
+        matches!(self.peek_nth(len), '\'' | '\n' | '\0')
+    }
+
+    /// Lexes a string up to its closing quote. If it contains an interpolation, only
+    /// the fragment before it is lexed, and the rest is lexed after the interpolation.
     fn string(&mut self, quote: char) -> TokenKind {
+        let (end, has_invalid_escape) = self.string_contents(quote, true);
+
+        match end {
+            StringEnd::Quote => TokenKind::String {
+                is_terminated: true,
+                has_invalid_escape,
+            },
+            StringEnd::Interpolation => TokenKind::StringFragment,
+            StringEnd::Unterminated => TokenKind::String {
+                is_terminated: false,
+                has_invalid_escape,
+            },
+        }
+    }
+
+    fn string_rest(&mut self, quote: char, start: usize) -> TokenKind {
+        if let (StringEnd::Unterminated, _) = self.string_contents(quote, true) {
+            self.error(start..self.pos, "unterminated string literal");
+        }
+        TokenKind::StringFragment
+    }
+
+    fn string_contents(&mut self, quote: char, interpolate: bool) -> (StringEnd, bool) {
+        let mut has_invalid_escape = false;
         loop {
+            if matches!(self.peek(), '\0' | '\n') {
+                return (StringEnd::Unterminated, has_invalid_escape);
+            }
+
+            if interpolate && self.peek() == '$' && self.peek_nth(1) == '{' {
+                self.mode = Mode::InterpStart { quote };
+                return (StringEnd::Interpolation, has_invalid_escape);
+            }
+
             match self.bump() {
-                '\0' => {
-                    return TokenKind::String {
-                        is_terminated: false,
-                    }
-                }
-                c if c == quote => {
-                    return TokenKind::String {
-                        is_terminated: true,
+                '\\' => {
+                    let start = self.pos - 1;
+                    if !self.escape() {
+                        has_invalid_escape = true;
+                        self.error(start..self.pos, "invalid escape sequence");
                     }
                 }
+                c if c == quote => return (StringEnd::Quote, has_invalid_escape),
                 _ => {}
             }
         }
     }
 
-    fn whitespace(&mut self) -> TokenKind {
-        while self.peek().is_whitespace() {
+    /// Lexes a string in triple quotes. Its contents are taken as written, without
+    /// escapes, so they can contain quotes and newlines.
+    fn multiline_string(&mut self) -> TokenKind {
+        self.bump();
+        self.bump();
+
+        let contents_start = self.pos;
+        let is_terminated = loop {
+            if self.pos == self.source.len() {
+                break false;
+            }
+            if self.peek() == '"' && self.peek_nth(1) == '"' && self.peek_nth(2) == '"' {
+                break true;
+            }
+            self.bump();
+        };
+        let indent = common_indent(&self.source[contents_start..self.pos]);
+
+        if is_terminated {
+            self.bump();
             self.bump();
+            self.bump();
+        }
+
+        TokenKind::MultilineString {
+            is_terminated,
+            indent,
+        }
+    }
+
+    fn escape(&mut self) -> bool {
+        if matches!(self.peek(), '\0' | '\n') {
+            return false;
         }
-        TokenKind::Whitespace
+        matches!(self.bump(), 'n' | 'r' | 't' | '0' | '\\' | '\'' | '"' | '$')
     }
 
-    fn ident(&mut self, c: char) -> TokenKind {
-        let mut ident = String::from(c);
+    fn whitespace(&mut self, first: char) -> TokenKind {
+        let mut newlines = u32::from(first == '\n');
+        while self.peek().is_whitespace() {
+            if self.bump() == '\n' {
+                newlines += 1;
+            }
+        }
+        TokenKind::Whitespace { newlines }
+    }
 
+    fn ident(&mut self, start: usize) -> TokenKind {
         while is_id_continue(self.peek()) {
-            ident.push(self.bump());
+            self.bump();
+        }
+
+        let word = &self.source[start..self.pos];
+        let keyword = match self.keywords {
+            Some(keywords) => keywords.get(word),
+            None => builtin_keyword(word),
+        };
+        keyword.unwrap_or(TokenKind::Ident)
+    }
+
+    fn number(&mut self, c: char) -> TokenKind {
+        let base = match (c, self.peek()) {
+            ('0', 'x') => Some(Base::Hexadecimal),
+            ('0', 'o') => Some(Base::Octal),
+            ('0', 'b') => Some(Base::Binary),
+            _ => None,
+        };
+
+        if let Some(base) = base {
+            self.bump();
+            let is_empty = self.digits(base) == 0;
+            return TokenKind::Int { base, is_empty };
+        }
+
+        self.digits(Base::Decimal);
+
+        let mut is_float = false;
+
+        if self.peek() == '.' && self.peek_nth(1).is_ascii_digit() {
+            self.bump();
+            self.digits(Base::Decimal);
+            is_float = true;
+        }
+
+        if self.at_exponent() {
+            self.bump();
+            if matches!(self.peek(), '+' | '-') {
+                self.bump();
+            }
+            self.digits(Base::Decimal);
+            is_float = true;
+        }
+
+        if is_float {
+            TokenKind::Float
+        } else {
+            TokenKind::Int {
+                base: Base::Decimal,
+                is_empty: false,
+            }
+        }
+    }
+
+    fn at_exponent(&self) -> bool {
+        if !matches!(self.peek(), 'e' | 'E') {
+            return false;
+        }
+
+        match self.peek_nth(1) {
+            '+' | '-' => self.peek_nth(2).is_ascii_digit(),
+            c => c.is_ascii_digit(),
         }
+    }
 
-        match ident.as_str() {
-            "fn" => TokenKind::Fn,
-            _ => TokenKind::Ident,
+    fn digits(&mut self, base: Base) -> usize {
+        let mut count = 0;
+        while self.peek().is_digit(base.radix()) {
+            self.bump();
+            count += 1;
         }
+        count
     }
 
     fn peek(&self) -> char {
-        self.chars.clone().next().unwrap_or('\0')
+        self.peek_nth(0)
+    }
+
+    fn peek_nth(&self, n: usize) -> char {
+        let rest = &self.source.as_bytes()[self.pos..];
+
+        // Only decode UTF-8 when a non-ASCII byte is within the lookahead.
+        match rest.get(..=n) {
+            Some(bytes) if bytes.is_ascii() => bytes[n] as char,
+            _ => self.source[self.pos..].chars().nth(n).unwrap_or('\0'),
+        }
     }
 
     fn bump(&mut self) -> char {
-        match self.chars.next() {
-            Some(c) => {
-                self.pos += c.len_utf8();
-                c
-            }
-            None => '\0',
+        let c = self.peek();
+
+        if self.pos == self.source.len() {
+            return '\0';
+        }
+
+        self.pos += c.len_utf8();
+        if c == '\n' {
+            self.line_col.line += 1;
+            self.line_col.col = 0;
+        } else {
+            self.line_col.col += 1;
+        }
+        c
+    }
+}
+
+/// The whitespace shared by the start of every line of a multiline string, other
+/// than the line with the opening quotes. Blank lines don't count, except for the
+/// last, which is the indentation of the closing quotes.
+fn common_indent(contents: &str) -> u32 {
+    let lines: Vec<&str> = contents.split('\n').skip(1).collect();
+
+    lines
+        .iter()
+        .enumerate()
+        .filter(|&(i, line)| i == lines.len() - 1 || !line.trim().is_empty())
+        .map(|(_, line)| line.len() - line.trim_start_matches([' ', '\t']).len())
+        .min()
+        .unwrap_or(0) as u32
+}
+
+/// Whether a full lex of the source would start a token at the offset, with nothing
+/// left open which would change how the rest is lexed.
+fn is_token_boundary(source: &str, offset: usize) -> bool {
+    let mut lexer = Lexer::new(source);
+
+    while lexer.pos < offset {
+        if lexer.next().is_none() {
+            return false;
         }
     }
+
+    lexer.pos == offset && lexer.mode == Mode::Normal && lexer.interpolations.is_empty()
 }
 
 fn is_id_start(c: char) -> bool {
-    matches!(c, 'a'..='z' | 'A'..='Z' | '_')
+    if c.is_ascii() {
+        c.is_ascii_alphabetic() || c == '_'
+    } else {
+        c.is_xid_start()
+    }
 }
 
 fn is_id_continue(c: char) -> bool {
-    is_id_start(c) || c.is_ascii_digit()
+    if c.is_ascii() {
+        c.is_ascii_alphanumeric() || c == '_'
+    } else {
+        c.is_xid_continue()
+    }
 }
 
 impl<'a> Iterator for Lexer<'a> {
@@ -145,48 +664,1003 @@ impl<'a> Iterator for Lexer<'a> {
         if self.pos < self.source.len() {
             Some(self.next_token())
         } else {
+            self.finish();
             None
         }
     }
 }
 
+/// Renders each token of the source on its own line as `Kind@start..end "text"`, with
+/// byte offsets, in the same style as a syntax tree. Meant for snapshot tests.
+pub fn dump_tokens(source: &str) -> String {
+    Lexer::new(source)
+        .map(|token| format!("{token}\n"))
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use expect_test::{expect, expect_file};
+
     fn check(source: &str, expected: &[TokenKind]) {
         let actual: Vec<TokenKind> = Lexer::new(source).map(Token::kind).collect();
         assert_eq!(actual, expected);
     }
 
+    fn string(is_terminated: bool, has_invalid_escape: bool) -> TokenKind {
+        TokenKind::String {
+            is_terminated,
+            has_invalid_escape,
+        }
+    }
+
+    fn whitespace(newlines: u32) -> TokenKind {
+        TokenKind::Whitespace { newlines }
+    }
+
+    fn int(base: Base) -> TokenKind {
+        TokenKind::Int {
+            base,
+            is_empty: false,
+        }
+    }
+
+    fn empty_int(base: Base) -> TokenKind {
+        TokenKind::Int {
+            base,
+            is_empty: true,
+        }
+    }
+
     #[test]
     fn test_whitespace() {
-        check("    ", &[TokenKind::Whitespace]);
-        check("\n\t", &[TokenKind::Whitespace]);
-        check("\r\n", &[TokenKind::Whitespace]);
-        check(" ", &[TokenKind::Whitespace]);
+        check("    ", &[whitespace(0)]);
+        check("\n\t", &[whitespace(1)]);
+        check("\r\n", &[whitespace(1)]);
+        check(" ", &[whitespace(0)]);
     }
 
     #[test]
-    fn test_ident() {
-        check("hello", &[TokenKind::Ident]);
+    fn test_whitespace_newlines() {
+        check("\n\n\n", &[whitespace(3)]);
+        check("  \n\t\n  \r\n ", &[whitespace(3)]);
         check(
-            "Hi There",
-            &[TokenKind::Ident, TokenKind::Whitespace, TokenKind::Ident],
+            "a \n\nb",
+            &[TokenKind::Ident, whitespace(2), TokenKind::Ident],
         );
-        check("with_underscore_and_numb3r", &[TokenKind::Ident]);
     }
 
     #[test]
-    fn test_unknown() {
-        check("\\", &[TokenKind::Unknown]);
+    fn test_shebang() {
         check(
-            "    \\\t",
+            "#!/usr/bin/env rue\nfn",
+            &[TokenKind::Shebang, whitespace(1), TokenKind::Fn],
+        );
+        check("#!", &[TokenKind::Shebang]);
+        check(
+            " #!/usr/bin",
+            &[
+                whitespace(0),
+                TokenKind::Hash,
+                TokenKind::Not,
+                TokenKind::Slash,
+                TokenKind::Ident,
+                TokenKind::Slash,
+                TokenKind::Ident,
+            ],
+        );
+        check(
+            "#![a]",
+            &[
+                TokenKind::Hash,
+                TokenKind::Not,
+                TokenKind::OpenBracket,
+                TokenKind::Ident,
+                TokenKind::CloseBracket,
+            ],
+        );
+        check(
+            "a\n#!b",
+            &[
+                TokenKind::Ident,
+                whitespace(1),
+                TokenKind::Hash,
+                TokenKind::Not,
+                TokenKind::Ident,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_block_comment() {
+        check(
+            "/* comment */",
+            &[TokenKind::BlockComment {
+                is_terminated: true,
+            }],
+        );
+        check(
+            "/* comment",
+            &[TokenKind::BlockComment {
+                is_terminated: false,
+            }],
+        );
+    }
+
+    #[test]
+    fn test_nested_block_comment() {
+        check(
+            "/* outer /* inner */ outer */",
+            &[TokenKind::BlockComment {
+                is_terminated: true,
+            }],
+        );
+        check(
+            "/* a /* b /* c */ b */ a */",
+            &[TokenKind::BlockComment {
+                is_terminated: true,
+            }],
+        );
+        check(
+            "/* outer /* inner */ outer",
+            &[TokenKind::BlockComment {
+                is_terminated: false,
+            }],
+        );
+        check(
+            "/* a */ b */",
             &[
-                TokenKind::Whitespace,
-                TokenKind::Unknown,
-                TokenKind::Whitespace,
+                TokenKind::BlockComment {
+                    is_terminated: true,
+                },
+                whitespace(0),
+                TokenKind::Ident,
+                whitespace(0),
+                TokenKind::Star,
+                TokenKind::Slash,
             ],
         );
     }
+
+    #[test]
+    fn test_ident() {
+        expect![[r#"
+            Ident@0..5 "hello"
+            Whitespace { newlines: 0 }@5..6 " "
+            Ident@6..14 "Hi_There"
+            Whitespace { newlines: 0 }@14..15 " "
+            Ident@15..26 "with_numb3r"
+        "#]]
+        .assert_eq(&dump_tokens("hello Hi_There with_numb3r"));
+    }
+
+    #[test]
+    fn test_unicode_ident() {
+        expect![[r#"
+            Ident@0..5 "café"
+            Whitespace { newlines: 0 }@5..6 " "
+            Ident@6..8 "λ"
+            Whitespace { newlines: 0 }@8..9 " "
+            Ident@9..17 "αβγ_1"
+            Whitespace { newlines: 0 }@17..18 " "
+            Ident@18..24 "日本"
+            Whitespace { newlines: 0 }@24..25 " "
+            Unknown@25..29 "🦀"
+            Whitespace { newlines: 0 }@29..30 " "
+            Ident@30..31 "a"
+            Unknown@31..35 "🦀"
+        "#]]
+        .assert_eq(&dump_tokens("café λ αβγ_1 日本 🦀 a🦀"));
+    }
+
+    #[test]
+    fn test_keywords() {
+        check("fn", &[TokenKind::Fn]);
+        check("let", &[TokenKind::Let]);
+        check("mut", &[TokenKind::Mut]);
+        check("if", &[TokenKind::If]);
+        check("else", &[TokenKind::Else]);
+        check("while", &[TokenKind::While]);
+        check("for", &[TokenKind::For]);
+        check("return", &[TokenKind::Return]);
+        check("break", &[TokenKind::Break]);
+        check("continue", &[TokenKind::Continue]);
+        check("struct", &[TokenKind::Struct]);
+        check("enum", &[TokenKind::Enum]);
+        check("pub", &[TokenKind::Pub]);
+        check("crate", &[TokenKind::Crate]);
+        check("super", &[TokenKind::Super]);
+        check("true", &[TokenKind::True]);
+        check("false", &[TokenKind::False]);
+    }
+
+    #[test]
+    fn test_keyword_prefix() {
+        check("fns", &[TokenKind::Ident]);
+        check("letter", &[TokenKind::Ident]);
+        check("iffy", &[TokenKind::Ident]);
+        check("_else", &[TokenKind::Ident]);
+        check("True", &[TokenKind::Ident]);
+        check("for2", &[TokenKind::Ident]);
+    }
+
+    #[test]
+    fn test_custom_keywords() {
+        let mut keywords = KeywordTable::default();
+        keywords.remove("fn");
+        keywords.insert("func", TokenKind::Fn);
+
+        let kinds: Vec<TokenKind> = Lexer::with_keywords("func fn let", &keywords)
+            .significant()
+            .map(Token::kind)
+            .collect();
+        assert_eq!(kinds, [TokenKind::Fn, TokenKind::Ident, TokenKind::Let]);
+    }
+
+    #[test]
+    #[should_panic = "Ident is not a keyword"]
+    fn test_custom_keyword_kind() {
+        KeywordTable::default().insert("x", TokenKind::Ident);
+    }
+
+    #[test]
+    fn test_identifier_run() {
+        let source = "fn fns let letter while whilst λ ".repeat(100);
+        let tokens: Vec<Token> = Lexer::new(&source).significant().collect();
+        assert_eq!(tokens.len(), 700);
+
+        for token in tokens {
+            let expected = match token.text() {
+                "fn" => TokenKind::Fn,
+                "let" => TokenKind::Let,
+                "while" => TokenKind::While,
+                _ => TokenKind::Ident,
+            };
+            assert_eq!(token.kind(), expected, "{token}");
+        }
+    }
+
+    #[test]
+    fn test_int() {
+        check("0", &[int(Base::Decimal)]);
+        check("42", &[int(Base::Decimal)]);
+        check("007", &[int(Base::Decimal)]);
+        check(
+            "1 23",
+            &[int(Base::Decimal), whitespace(0), int(Base::Decimal)],
+        );
+    }
+
+    #[test]
+    fn test_radix_int() {
+        check("0x1F", &[int(Base::Hexadecimal)]);
+        check("0xdeadBEEF", &[int(Base::Hexadecimal)]);
+        check("0o17", &[int(Base::Octal)]);
+        check("0b1010", &[int(Base::Binary)]);
+    }
+
+    #[test]
+    fn test_empty_radix_int() {
+        check("0x", &[empty_int(Base::Hexadecimal)]);
+        check("0o", &[empty_int(Base::Octal)]);
+        check("0b2", &[empty_int(Base::Binary), int(Base::Decimal)]);
+        check("0o8", &[empty_int(Base::Octal), int(Base::Decimal)]);
+        check("0xg", &[empty_int(Base::Hexadecimal), TokenKind::Ident]);
+    }
+
+    #[test]
+    fn test_float() {
+        check("3.14", &[TokenKind::Float]);
+        check("10.0", &[TokenKind::Float]);
+        check("5.", &[int(Base::Decimal), TokenKind::Dot]);
+        check(
+            "5.x",
+            &[int(Base::Decimal), TokenKind::Dot, TokenKind::Ident],
+        );
+        check(
+            "1.2.3",
+            &[TokenKind::Float, TokenKind::Dot, int(Base::Decimal)],
+        );
+        check(
+            "0x1.5",
+            &[int(Base::Hexadecimal), TokenKind::Dot, int(Base::Decimal)],
+        );
+    }
+
+    #[test]
+    fn test_float_exponent() {
+        check("1e10", &[TokenKind::Float]);
+        check("2.5e-3", &[TokenKind::Float]);
+        check("6.02E+23", &[TokenKind::Float]);
+        check("1E5", &[TokenKind::Float]);
+    }
+
+    #[test]
+    fn test_malformed_float_exponent() {
+        check("1e", &[int(Base::Decimal), TokenKind::Ident]);
+        check("2.5e", &[TokenKind::Float, TokenKind::Ident]);
+        check(
+            "1e-",
+            &[int(Base::Decimal), TokenKind::Ident, TokenKind::Minus],
+        );
+        check("1ex", &[int(Base::Decimal), TokenKind::Ident]);
+    }
+
+    #[test]
+    fn test_char() {
+        check(
+            "'a'",
+            &[TokenKind::Char {
+                is_terminated: true,
+            }],
+        );
+        check(
+            "'\\n'",
+            &[TokenKind::Char {
+                is_terminated: true,
+            }],
+        );
+        check(
+            "'\\''",
+            &[TokenKind::Char {
+                is_terminated: true,
+            }],
+        );
+        check(
+            "'\\\\'",
+            &[TokenKind::Char {
+                is_terminated: true,
+            }],
+        );
+        check(
+            "'λ'",
+            &[TokenKind::Char {
+                is_terminated: true,
+            }],
+        );
+    }
+
+    #[test]
+    fn test_unterminated_char() {
+        check(
+            "'a",
+            &[TokenKind::Char {
+                is_terminated: false,
+            }],
+        );
+        check(
+            "'a\nb",
+            &[
+                TokenKind::Char {
+                    is_terminated: false,
+                },
+                whitespace(1),
+                TokenKind::Ident,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_single_quoted_string() {
+        check("'abc'", &[string(true, false)]);
+        check("''", &[string(true, false)]);
+    }
+
+    #[test]
+    fn test_string_escapes() {
+        check(r#""\n""#, &[string(true, false)]);
+        check(r#""\\""#, &[string(true, false)]);
+        check(r#""a\"b""#, &[string(true, false)]);
+        check(r#""\q""#, &[string(true, true)]);
+        check(r#""\""#, &[string(false, false)]);
+    }
+
+    #[test]
+    fn test_byte_literals() {
+        check(
+            r#"b"abc""#,
+            &[TokenKind::ByteString {
+                is_terminated: true,
+                has_invalid_escape: false,
+                has_non_ascii: false,
+            }],
+        );
+        check(
+            "b'x'",
+            &[TokenKind::Byte {
+                is_terminated: true,
+                has_non_ascii: false,
+            }],
+        );
+        check(
+            r#"b"${a}""#,
+            &[TokenKind::ByteString {
+                is_terminated: true,
+                has_invalid_escape: false,
+                has_non_ascii: false,
+            }],
+        );
+        check("b bc", &[TokenKind::Ident, whitespace(0), TokenKind::Ident]);
+    }
+
+    #[test]
+    fn test_non_ascii_byte_literal() {
+        let source = "b'é' b\"λx\"";
+        let mut lexer = Lexer::new(source);
+        let kinds: Vec<TokenKind> = lexer.by_ref().map(Token::kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                TokenKind::Byte {
+                    is_terminated: true,
+                    has_non_ascii: true,
+                },
+                whitespace(0),
+                TokenKind::ByteString {
+                    is_terminated: true,
+                    has_invalid_escape: false,
+                    has_non_ascii: true,
+                },
+            ]
+        );
+
+        let errors = lexer.take_diagnostics();
+        let spans: Vec<&str> = errors
+            .iter()
+            .map(|error| &source[error.span.clone()])
+            .collect();
+        assert_eq!(spans, ["b'é'", "b\"λx\""]);
+        assert_eq!(errors[0].message, "non-ASCII character in byte literal");
+    }
+
+    #[test]
+    fn test_multiline_string() {
+        let source = "\"\"\"\n    hello\n      \"world\"\n    \"\"\"";
+        let token = Lexer::new(source).next().unwrap();
+        assert_eq!(token.text(), source);
+        assert_eq!(
+            token.kind(),
+            TokenKind::MultilineString {
+                is_terminated: true,
+                indent: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn test_multiline_string_mixed_indent() {
+        check(
+            "\"\"\"a\n\t\tb\n\n   c\n\t  d\"\"\" x",
+            &[
+                TokenKind::MultilineString {
+                    is_terminated: true,
+                    indent: 2,
+                },
+                whitespace(0),
+                TokenKind::Ident,
+            ],
+        );
+        check(
+            "\"\"\"\n  a\n\"\"\"",
+            &[TokenKind::MultilineString {
+                is_terminated: true,
+                indent: 0,
+            }],
+        );
+        check(
+            "\"\"\"abc",
+            &[TokenKind::MultilineString {
+                is_terminated: false,
+                indent: 0,
+            }],
+        );
+    }
+
+    #[test]
+    fn test_interpolation() {
+        let tokens: Vec<(TokenKind, &str)> = Lexer::new(r#""hello ${name}!""#)
+            .map(|token| (token.kind(), token.text()))
+            .collect();
+        assert_eq!(
+            tokens,
+            [
+                (TokenKind::StringFragment, "\"hello "),
+                (TokenKind::InterpStart, "${"),
+                (TokenKind::Ident, "name"),
+                (TokenKind::InterpEnd, "}"),
+                (TokenKind::StringFragment, "!\""),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_interpolation_nested_braces() {
+        check(
+            r#""${ if a { b } }${c}""#,
+            &[
+                TokenKind::StringFragment,
+                TokenKind::InterpStart,
+                whitespace(0),
+                TokenKind::If,
+                whitespace(0),
+                TokenKind::Ident,
+                whitespace(0),
+                TokenKind::OpenBrace,
+                whitespace(0),
+                TokenKind::Ident,
+                whitespace(0),
+                TokenKind::CloseBrace,
+                whitespace(0),
+                TokenKind::InterpEnd,
+                TokenKind::StringFragment,
+                TokenKind::InterpStart,
+                TokenKind::Ident,
+                TokenKind::InterpEnd,
+                TokenKind::StringFragment,
+            ],
+        );
+        check(
+            r#""${"${a}"}""#,
+            &[
+                TokenKind::StringFragment,
+                TokenKind::InterpStart,
+                TokenKind::StringFragment,
+                TokenKind::InterpStart,
+                TokenKind::Ident,
+                TokenKind::InterpEnd,
+                TokenKind::StringFragment,
+                TokenKind::InterpEnd,
+                TokenKind::StringFragment,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_escaped_interpolation() {
+        check(r#""\${a}""#, &[string(true, false)]);
+    }
+
+    #[test]
+    fn test_unterminated_interpolation() {
+        let source = "\"a ${b\n";
+        let mut lexer = Lexer::new(source);
+        let kinds: Vec<TokenKind> = lexer.by_ref().map(Token::kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                TokenKind::StringFragment,
+                TokenKind::InterpStart,
+                TokenKind::Ident,
+                whitespace(1),
+            ]
+        );
+
+        let errors = lexer.take_diagnostics();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(&source[errors[0].span.clone()], "${");
+        assert_eq!(errors[0].message, "unterminated interpolation");
+
+        let mut lexer = Lexer::new("\"${a}");
+        lexer.by_ref().for_each(drop);
+        let errors = lexer.take_diagnostics();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message, "unterminated string literal");
+    }
+
+    #[test]
+    fn test_arithmetic_operators() {
+        check(
+            "a + b * c / d % e",
+            &[
+                TokenKind::Ident,
+                whitespace(0),
+                TokenKind::Plus,
+                whitespace(0),
+                TokenKind::Ident,
+                whitespace(0),
+                TokenKind::Star,
+                whitespace(0),
+                TokenKind::Ident,
+                whitespace(0),
+                TokenKind::Slash,
+                whitespace(0),
+                TokenKind::Ident,
+                whitespace(0),
+                TokenKind::Percent,
+                whitespace(0),
+                TokenKind::Ident,
+            ],
+        );
+        check("/", &[TokenKind::Slash]);
+        check("-1", &[TokenKind::Minus, int(Base::Decimal)]);
+    }
+
+    #[test]
+    fn test_arrow() {
+        expect![[r#"
+            Arrow@0..2 "->"
+            Whitespace { newlines: 0 }@2..3 " "
+            Minus@3..4 "-"
+            Whitespace { newlines: 0 }@4..5 " "
+            GreaterThan@5..6 ">"
+            Whitespace { newlines: 0 }@6..7 " "
+            Minus@7..8 "-"
+            Arrow@8..10 "->"
+            Whitespace { newlines: 0 }@10..11 " "
+            Arrow@11..13 "->"
+            GreaterThan@13..14 ">"
+        "#]]
+        .assert_eq(&dump_tokens("-> - > --> ->>"));
+    }
+
+    #[test]
+    fn test_comparison_operators() {
+        check(">", &[TokenKind::GreaterThan]);
+        check(">=", &[TokenKind::GreaterThanEquals]);
+        check("<", &[TokenKind::LessThan]);
+        check("<=", &[TokenKind::LessThanEquals]);
+        check("=", &[TokenKind::Equals]);
+        check("==", &[TokenKind::EqualEquals]);
+        check("!", &[TokenKind::Not]);
+        check("!=", &[TokenKind::NotEquals]);
+        check("===", &[TokenKind::EqualEquals, TokenKind::Equals]);
+        check(
+            "a != b",
+            &[
+                TokenKind::Ident,
+                whitespace(0),
+                TokenKind::NotEquals,
+                whitespace(0),
+                TokenKind::Ident,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_logical_operators() {
+        check(
+            "a && b",
+            &[
+                TokenKind::Ident,
+                whitespace(0),
+                TokenKind::AndAnd,
+                whitespace(0),
+                TokenKind::Ident,
+            ],
+        );
+        check(
+            "a || b",
+            &[
+                TokenKind::Ident,
+                whitespace(0),
+                TokenKind::OrOr,
+                whitespace(0),
+                TokenKind::Ident,
+            ],
+        );
+        check("!flag", &[TokenKind::Not, TokenKind::Ident]);
+    }
+
+    #[test]
+    fn test_bitwise_operators() {
+        check(
+            "a & b",
+            &[
+                TokenKind::Ident,
+                whitespace(0),
+                TokenKind::Amp,
+                whitespace(0),
+                TokenKind::Ident,
+            ],
+        );
+        check(
+            "a|b",
+            &[TokenKind::Ident, TokenKind::Pipe, TokenKind::Ident],
+        );
+        check(
+            "a^b",
+            &[TokenKind::Ident, TokenKind::Caret, TokenKind::Ident],
+        );
+        check("&&&", &[TokenKind::AndAnd, TokenKind::Amp]);
+    }
+
+    #[test]
+    fn test_assignment_operators() {
+        let assign = |op| {
+            [
+                TokenKind::Ident,
+                whitespace(0),
+                op,
+                whitespace(0),
+                int(Base::Decimal),
+            ]
+        };
+        check("x = 1", &assign(TokenKind::Equals));
+        check("x == 1", &assign(TokenKind::EqualEquals));
+        check("x += 1", &assign(TokenKind::PlusEquals));
+        check("x -= 1", &assign(TokenKind::MinusEquals));
+        check("x *= 1", &assign(TokenKind::StarEquals));
+        check("x /= 1", &assign(TokenKind::SlashEquals));
+        check("x %= 1", &assign(TokenKind::PercentEquals));
+        check("+==", &[TokenKind::PlusEquals, TokenKind::Equals]);
+    }
+
+    #[test]
+    fn test_punctuation() {
+        check("a.b", &[TokenKind::Ident, TokenKind::Dot, TokenKind::Ident]);
+        check(
+            "a::b",
+            &[TokenKind::Ident, TokenKind::ColonColon, TokenKind::Ident],
+        );
+        check(
+            "a: b",
+            &[
+                TokenKind::Ident,
+                TokenKind::Colon,
+                whitespace(0),
+                TokenKind::Ident,
+            ],
+        );
+        check(
+            "a, b",
+            &[
+                TokenKind::Ident,
+                TokenKind::Comma,
+                whitespace(0),
+                TokenKind::Ident,
+            ],
+        );
+        check(
+            "a; b",
+            &[
+                TokenKind::Ident,
+                TokenKind::Semicolon,
+                whitespace(0),
+                TokenKind::Ident,
+            ],
+        );
+        check(":::", &[TokenKind::ColonColon, TokenKind::Colon]);
+        check(
+            "#[a]",
+            &[
+                TokenKind::Hash,
+                TokenKind::OpenBracket,
+                TokenKind::Ident,
+                TokenKind::CloseBracket,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_new_at() {
+        let source = "fn main() {\n\t\"héllo ${a}\" // 🦀\n}";
+        let tokens: Vec<Token> = Lexer::new(source).collect();
+
+        // Tokens after the start of an interpolated string depend on it.
+        let string = source.find('"').unwrap() + 1..source.rfind('"').unwrap() + 1;
+
+        for (i, token) in tokens.iter().enumerate() {
+            let offset = token.span().start;
+            if string.contains(&offset) {
+                continue;
+            }
+
+            let mut lexer = Lexer::new_at(source, offset);
+            assert_eq!(lexer.line_col(), LineIndex::new(source).line_col(offset));
+
+            let suffix: Vec<Token> = lexer.by_ref().collect();
+            assert_eq!(suffix, tokens[i..], "lexing from {offset}");
+        }
+    }
+
+    #[test]
+    #[should_panic = "not a token boundary"]
+    fn test_new_at_inside_token() {
+        Lexer::new_at("main", 2);
+    }
+
+    #[test]
+    fn test_line_col() {
+        let source = "fn main() {\n\t\"héllo\" // 🦀\n}";
+        let index = LineIndex::new(source);
+        let mut lexer = Lexer::new(source);
+        let mut pos = 0;
+
+        while let Some(token) = lexer.next() {
+            pos += token.text().len();
+            assert_eq!(lexer.line_col(), index.line_col(pos));
+        }
+
+        assert_eq!(lexer.line_col(), LineCol { line: 2, col: 1 });
+    }
+
+    #[test]
+    fn test_spans() {
+        let source = "fn main() -> Int {\n    /* λ */ 0x1F + 'c'\n}";
+        let mut end = 0;
+
+        for token in Lexer::new(source) {
+            assert_eq!(token.span().start, end);
+            assert_eq!(&source[token.span()], token.text());
+            end = token.span().end;
+        }
+
+        assert_eq!(end, source.len());
+    }
+
+    #[test]
+    fn test_peek_nth() {
+        let mut lexer = Lexer::new("aλ");
+        assert_eq!(lexer.peek_nth(0), 'a');
+        assert_eq!(lexer.peek_nth(1), 'λ');
+        assert_eq!(lexer.peek_nth(2), '\0');
+        assert_eq!(lexer.peek_nth(100), '\0');
+
+        lexer.bump();
+        assert_eq!(lexer.peek_nth(0), 'λ');
+        assert_eq!(lexer.peek_nth(1), '\0');
+
+        lexer.bump();
+        assert_eq!(lexer.peek(), '\0');
+    }
+
+    #[test]
+    fn test_unterminated_string() {
+        check(r#""abc"#, &[string(false, false)]);
+        check(
+            "\"abc\ndef",
+            &[string(false, false), whitespace(1), TokenKind::Ident],
+        );
+        check(
+            "'abc\n'x'",
+            &[
+                string(false, false),
+                whitespace(1),
+                TokenKind::Char {
+                    is_terminated: true,
+                },
+            ],
+        );
+        check(
+            "\"abc\\\n\"",
+            &[string(false, true), whitespace(1), string(false, false)],
+        );
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        let source = "let x = \"a\\qb\\z\" @ 0x;\n'c\n/* end";
+        let mut lexer = Lexer::new(source);
+        lexer.by_ref().for_each(drop);
+
+        let errors = lexer.take_diagnostics();
+        let spans: Vec<(&str, &str)> = errors
+            .iter()
+            .map(|error| (&source[error.span.clone()], error.message.as_str()))
+            .collect();
+
+        assert_eq!(
+            spans,
+            [
+                ("\\q", "invalid escape sequence"),
+                ("\\z", "invalid escape sequence"),
+                ("@", "unknown character"),
+                ("0x", "missing digits after integer base prefix"),
+                ("'c", "unterminated character literal"),
+                ("/* end", "unterminated block comment"),
+            ]
+        );
+        assert!(lexer.take_diagnostics().is_empty());
+    }
+
+    #[test]
+    fn test_no_diagnostics() {
+        let mut lexer = Lexer::new("fn main() -> Int { 'a' + \"\\n\" }");
+        lexer.by_ref().for_each(drop);
+        assert!(lexer.take_diagnostics().is_empty());
+    }
+
+    #[test]
+    fn test_owned_tokens() {
+        let expected = "fn main() {\n    \"λ\" // hi\n}";
+
+        let owned: Vec<OwnedToken> = {
+            let source = expected.to_string();
+            Lexer::new(&source).map(Token::to_owned).collect()
+        };
+
+        let borrowed: Vec<Token> = Lexer::new(expected).collect();
+        let round_trip: Vec<Token> = owned.iter().map(OwnedToken::as_token).collect();
+        assert_eq!(round_trip, borrowed);
+
+        let text: String = owned.iter().map(OwnedToken::text).collect();
+        assert_eq!(text, expected);
+    }
+
+    #[test]
+    fn test_significant() {
+        let source = "// entry point\nfn main() /* no params */ {}";
+
+        let all: Vec<TokenKind> = Lexer::new(source).map(Token::kind).collect();
+        assert_eq!(
+            all,
+            [
+                TokenKind::LineComment,
+                whitespace(1),
+                TokenKind::Fn,
+                whitespace(0),
+                TokenKind::Ident,
+                TokenKind::OpenParen,
+                TokenKind::CloseParen,
+                whitespace(0),
+                TokenKind::BlockComment {
+                    is_terminated: true
+                },
+                whitespace(0),
+                TokenKind::OpenBrace,
+                TokenKind::CloseBrace,
+            ]
+        );
+
+        let significant: Vec<TokenKind> =
+            Lexer::new(source).significant().map(Token::kind).collect();
+        assert_eq!(
+            significant,
+            [
+                TokenKind::Fn,
+                TokenKind::Ident,
+                TokenKind::OpenParen,
+                TokenKind::CloseParen,
+                TokenKind::OpenBrace,
+                TokenKind::CloseBrace,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sample_file() {
+        let source = include_str!("../tests/data/sample.rue");
+        let mut dump = String::new();
+
+        for token in Lexer::new(source) {
+            dump.push_str(&format!("{token}\n"));
+        }
+
+        expect_file!["../tests/data/sample.tokens"].assert_eq(&dump);
+    }
+
+    #[test]
+    fn test_token_at() {
+        let source = "let value = 42;";
+
+        let token = token_at(source, 6).unwrap();
+        assert_eq!(token.kind(), TokenKind::Ident);
+        assert_eq!(token.text(), "value");
+
+        let token = token_at(source, 4).unwrap();
+        assert_eq!(token.text(), "value");
+
+        let token = token_at(source, 9).unwrap();
+        assert_eq!(token.kind(), whitespace(0));
+
+        let token = token_at(source, 12).unwrap();
+        assert_eq!(token.text(), "42");
+
+        let token = token_at(source, 14).unwrap();
+        assert_eq!(token.kind(), TokenKind::Semicolon);
+
+        assert_eq!(token_at(source, 15), None);
+        assert_eq!(token_at(source, 100), None);
+    }
+
+    #[test]
+    fn test_unknown() {
+        check("\\", &[TokenKind::Unknown]);
+        check(
+            "    \\\t",
+            &[whitespace(0), TokenKind::Unknown, whitespace(0)],
+        );
+    }
 }
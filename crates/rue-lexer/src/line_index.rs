@@ -0,0 +1,189 @@
+/// A zero-based line and column. Columns count characters rather than bytes,
+/// so a tab or a multibyte character each advance the column by one.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LineCol {
+    pub line: u32,
+    pub col: u32,
+}
+
+/// Maps byte offsets in a source file to line and column positions, and back.
+/// Lines can end with either `\n` or `\r\n`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineIndex {
+    line_starts: Vec<usize>,
+    line_ends: Vec<usize>,
+    multibyte_chars: Vec<(usize, usize)>,
+}
+
+impl LineIndex {
+    pub fn new(source: &str) -> Self {
+        let mut line_starts = vec![0];
+        let mut line_ends = Vec::new();
+        let mut multibyte_chars = Vec::new();
+
+        for (offset, c) in source.char_indices() {
+            if c == '\n' {
+                let is_crlf = source[..offset].ends_with('\r');
+                line_ends.push(if is_crlf { offset - 1 } else { offset });
+                line_starts.push(offset + 1);
+            }
+            if c.len_utf8() > 1 {
+                multibyte_chars.push((offset, c.len_utf8()));
+            }
+        }
+
+        line_ends.push(source.len());
+
+        Self {
+            line_starts,
+            line_ends,
+            multibyte_chars,
+        }
+    }
+
+    /// Offsets past the end of a line, including inside of a `\r\n`, are clamped
+    /// to the end of that line, and offsets past the end of the file to its end.
+    pub fn line_col(&self, offset: usize) -> LineCol {
+        let line = self.line_starts.partition_point(|&start| start <= offset) - 1;
+        let line_start = self.line_starts[line];
+        let offset = offset.min(self.line_ends[line]);
+
+        let start = self
+            .multibyte_chars
+            .partition_point(|&(pos, _)| pos < line_start);
+        let end = self
+            .multibyte_chars
+            .partition_point(|&(pos, _)| pos < offset);
+        let extra_bytes: usize = self.multibyte_chars[start..end]
+            .iter()
+            .map(|(_, len)| len - 1)
+            .sum();
+
+        LineCol {
+            line: line as u32,
+            col: (offset - line_start - extra_bytes) as u32,
+        }
+    }
+
+    /// The byte offset of a line and column. Positions past the end of a line
+    /// are clamped to the end of that line, and lines past the end of the file
+    /// to the end of the last line.
+    pub fn offset(&self, line_col: LineCol) -> usize {
+        let line = (line_col.line as usize).min(self.line_starts.len() - 1);
+        let line_start = self.line_starts[line];
+        let line_end = self.line_ends[line];
+
+        if line_col.line as usize > line {
+            return line_end;
+        }
+
+        let start = self
+            .multibyte_chars
+            .partition_point(|&(pos, _)| pos < line_start);
+        let mut offset = line_start + line_col.col as usize;
+
+        for &(pos, len) in &self.multibyte_chars[start..] {
+            if pos >= offset || pos >= line_end {
+                break;
+            }
+            offset += len - 1;
+        }
+
+        offset.min(line_end)
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_col(line: u32, col: u32) -> LineCol {
+        LineCol { line, col }
+    }
+
+    #[test]
+    fn test_single_line() {
+        let index = LineIndex::new("hello");
+        assert_eq!(index.line_col(0), line_col(0, 0));
+        assert_eq!(index.line_col(3), line_col(0, 3));
+        assert_eq!(index.line_col(5), line_col(0, 5));
+    }
+
+    #[test]
+    fn test_multiple_lines() {
+        let index = LineIndex::new("fn\nmain\n\nx");
+        assert_eq!(index.line_col(2), line_col(0, 2));
+        assert_eq!(index.line_col(3), line_col(1, 0));
+        assert_eq!(index.line_col(7), line_col(1, 4));
+        assert_eq!(index.line_col(8), line_col(2, 0));
+        assert_eq!(index.line_col(9), line_col(3, 0));
+    }
+
+    #[test]
+    fn test_tabs() {
+        let index = LineIndex::new("\t\tx\n\ty");
+        assert_eq!(index.line_col(2), line_col(0, 2));
+        assert_eq!(index.line_col(5), line_col(1, 1));
+    }
+
+    #[test]
+    fn test_multibyte() {
+        let source = "é λ\n🦀x";
+        let index = LineIndex::new(source);
+        assert_eq!(index.line_col(source.find('λ').unwrap()), line_col(0, 2));
+        assert_eq!(index.line_col(source.find('\n').unwrap()), line_col(0, 3));
+        assert_eq!(index.line_col(source.find('x').unwrap()), line_col(1, 1));
+    }
+
+    #[test]
+    fn test_mixed_line_endings() {
+        let source = "fn\r\nmain\n\r\nx";
+        let index = LineIndex::new(source);
+        assert_eq!(index.line_count(), 4);
+        assert_eq!(index.line_col(2), line_col(0, 2));
+        assert_eq!(index.line_col(3), line_col(0, 2));
+        assert_eq!(index.line_col(4), line_col(1, 0));
+        assert_eq!(index.line_col(8), line_col(1, 4));
+        assert_eq!(index.line_col(9), line_col(2, 0));
+        assert_eq!(index.line_col(11), line_col(3, 0));
+    }
+
+    #[test]
+    fn test_past_end() {
+        let index = LineIndex::new("ab\ncd");
+        assert_eq!(index.line_col(5), line_col(1, 2));
+        assert_eq!(index.line_col(100), line_col(1, 2));
+    }
+
+    #[test]
+    fn test_offset() {
+        let source = "fn\r\nmain\n\r\nx";
+        let index = LineIndex::new(source);
+
+        for offset in 0..=source.len() {
+            if source[..offset].ends_with('\r') {
+                continue;
+            }
+            assert_eq!(index.offset(index.line_col(offset)), offset);
+        }
+
+        assert_eq!(index.offset(line_col(0, 10)), 2);
+        assert_eq!(index.offset(line_col(2, 0)), 9);
+        assert_eq!(index.offset(line_col(2, 5)), 9);
+        assert_eq!(index.offset(line_col(10, 0)), source.len());
+    }
+
+    #[test]
+    fn test_offset_multibyte() {
+        let source = "é λ\n🦀x";
+        let index = LineIndex::new(source);
+        assert_eq!(index.offset(line_col(0, 2)), source.find('λ').unwrap());
+        assert_eq!(index.offset(line_col(0, 3)), source.find('\n').unwrap());
+        assert_eq!(index.offset(line_col(1, 1)), source.find('x').unwrap());
+        assert_eq!(index.offset(line_col(1, 9)), source.len());
+    }
+}
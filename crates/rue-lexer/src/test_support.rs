@@ -0,0 +1,69 @@
+use proptest::prelude::*;
+
+use crate::Lexer;
+
+/// Whether the tokens of the source fit back together into exactly the source, with
+/// no bytes lost or duplicated and no empty tokens.
+pub fn lex_roundtrip(source: &str) -> bool {
+    let mut rebuilt = String::new();
+
+    for token in Lexer::new(source) {
+        if token.text().is_empty() || token.span().start != rebuilt.len() {
+            return false;
+        }
+        rebuilt.push_str(token.text());
+    }
+
+    rebuilt == source
+}
+
+/// Inputs which are easy to get wrong, such as unterminated literals and comments,
+/// incomplete numbers and multibyte characters.
+const REGRESSIONS: &[&str] = &[
+    "",
+    "\"",
+    "\"\\",
+    "\"\\\"",
+    "'",
+    "'\\",
+    "''",
+    "/*",
+    "/* /* */",
+    "/*/",
+    "//",
+    "// \r\n",
+    "0x",
+    "0b2",
+    "1e",
+    "1e+",
+    "1.",
+    "1..2",
+    "1.e5",
+    "\u{0}",
+    "é",
+    "日本",
+    "a\u{200d}b",
+    "🦀",
+    "\r",
+    "-> - >",
+    "::::",
+];
+
+#[test]
+fn roundtrip_regressions() {
+    for source in REGRESSIONS {
+        assert!(lex_roundtrip(source), "failed to roundtrip {source:?}");
+    }
+}
+
+proptest! {
+    #[test]
+    fn roundtrip_any_string(source in any::<String>()) {
+        prop_assert!(lex_roundtrip(&source));
+    }
+
+    #[test]
+    fn roundtrip_rue_like(source in r#"([a-z0-9_.+\-*/%=!<>&|^:;,(){}'"\\ \n\t]|/\*|\*/|//|0x|0b|e\+){0,64}"#) {
+        prop_assert!(lex_roundtrip(&source));
+    }
+}
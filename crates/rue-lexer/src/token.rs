@@ -1,14 +1,17 @@
+use std::{fmt, ops::Range};
+
 use crate::TokenKind;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Token<'a> {
     kind: TokenKind,
     text: &'a str,
+    start: usize,
 }
 
 impl<'a> Token<'a> {
-    pub fn new(kind: TokenKind, text: &'a str) -> Self {
-        Self { kind, text }
+    pub fn new(kind: TokenKind, text: &'a str, start: usize) -> Self {
+        Self { kind, text, start }
     }
 
     pub fn kind(self) -> TokenKind {
@@ -18,4 +21,84 @@ impl<'a> Token<'a> {
     pub fn text(self) -> &'a str {
         self.text
     }
+
+    /// The byte range of the token within the source.
+    pub fn span(self) -> Range<usize> {
+        self.start..self.start + self.text.len()
+    }
+
+    /// Whether the byte offset falls within the token. Spans are half-open, so an
+    /// offset between two tokens belongs to the one which starts there.
+    pub fn contains(self, offset: usize) -> bool {
+        self.span().contains(&offset)
+    }
+
+    /// Copies the token's text so it can outlive the source.
+    pub fn to_owned(self) -> OwnedToken {
+        OwnedToken {
+            kind: self.kind,
+            text: self.text.to_string(),
+            start: self.start,
+        }
+    }
+}
+
+impl<'a> fmt::Display for Token<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}@{:?} {:?}", self.kind, self.span(), self.text)
+    }
+}
+
+/// A [`Token`] which owns its text rather than borrowing the source.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct OwnedToken {
+    kind: TokenKind,
+    text: String,
+    start: usize,
+}
+
+impl OwnedToken {
+    pub fn kind(&self) -> TokenKind {
+        self.kind
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn span(&self) -> Range<usize> {
+        self.start..self.start + self.text.len()
+    }
+
+    pub fn as_token(&self) -> Token<'_> {
+        Token::new(self.kind, &self.text, self.start)
+    }
+}
+
+impl<'a> From<Token<'a>> for OwnedToken {
+    fn from(token: Token<'a>) -> Self {
+        token.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Lexer;
+
+    #[test]
+    fn test_display() {
+        let tokens: Vec<String> = Lexer::new("fn λ\n")
+            .map(|token| token.to_string())
+            .collect();
+
+        assert_eq!(
+            tokens,
+            [
+                r#"Fn@0..2 "fn""#,
+                r#"Whitespace { newlines: 0 }@2..3 " ""#,
+                r#"Ident@3..5 "λ""#,
+                r#"Whitespace { newlines: 1 }@5..6 "\n""#,
+            ]
+        );
+    }
 }
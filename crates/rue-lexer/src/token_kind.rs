@@ -1,20 +1,394 @@
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TokenKind {
     Unknown,
-    Whitespace,
-    BlockComment { is_terminated: bool },
+    /// A run of whitespace, with the number of line breaks in it so that blank lines
+    /// can be told apart from other whitespace.
+    Whitespace {
+        newlines: u32,
+    },
+    BlockComment {
+        is_terminated: bool,
+    },
     LineComment,
+    /// A line such as `#!/usr/bin/env rue` at the very start of the source.
+    Shebang,
 
-    String { is_terminated: bool },
+    String {
+        is_terminated: bool,
+        has_invalid_escape: bool,
+    },
+    Char {
+        is_terminated: bool,
+    },
+    ByteString {
+        is_terminated: bool,
+        has_invalid_escape: bool,
+        has_non_ascii: bool,
+    },
+    Byte {
+        is_terminated: bool,
+        has_non_ascii: bool,
+    },
+    /// A string in triple quotes, which can span multiple lines. The indentation
+    /// shared by its lines is stripped from its value, but kept in the token's text.
+    MultilineString {
+        is_terminated: bool,
+        indent: u32,
+    },
+    /// Part of a string which contains interpolations, such as `"a ${` or `} b"`.
+    StringFragment,
+    InterpStart,
+    InterpEnd,
     Ident,
+    Int {
+        base: Base,
+        is_empty: bool,
+    },
+    Float,
 
     Fn,
+    Let,
+    Mut,
+    If,
+    Else,
+    While,
+    For,
+    Return,
+    Break,
+    Continue,
+    Struct,
+    Enum,
+    Pub,
+    Crate,
+    Super,
+    True,
+    False,
 
     OpenParen,
     CloseParen,
     OpenBrace,
     CloseBrace,
+    OpenBracket,
+    CloseBracket,
 
+    Hash,
+    Dot,
+    Comma,
+    Colon,
+    ColonColon,
+    Semicolon,
+
+    Arrow,
+
+    Equals,
+    Not,
+
+    EqualEquals,
+    NotEquals,
+    LessThan,
     GreaterThan,
+    LessThanEquals,
+    GreaterThanEquals,
+
+    Plus,
     Minus,
+    Star,
+    Slash,
+    Percent,
+
+    PlusEquals,
+    MinusEquals,
+    StarEquals,
+    SlashEquals,
+    PercentEquals,
+
+    Amp,
+    Pipe,
+    Caret,
+    AndAnd,
+    OrOr,
+}
+
+impl TokenKind {
+    /// A human readable name for the kind of token, for use in error messages.
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::Unknown => "unknown character",
+            Self::Whitespace { .. } => "whitespace",
+            Self::BlockComment { .. } => "block comment",
+            Self::LineComment => "line comment",
+            Self::Shebang => "shebang",
+
+            Self::String { .. } => "string literal",
+            Self::Char { .. } => "character literal",
+            Self::ByteString { .. } => "byte string literal",
+            Self::Byte { .. } => "byte literal",
+            Self::MultilineString { .. } => "string literal",
+            Self::StringFragment => "string fragment",
+            Self::InterpStart => "`${`",
+            Self::InterpEnd => "end of interpolation",
+            Self::Ident => "identifier",
+            Self::Int { .. } => "integer literal",
+            Self::Float => "float literal",
+
+            Self::Fn => "`fn`",
+            Self::Let => "`let`",
+            Self::Mut => "`mut`",
+            Self::If => "`if`",
+            Self::Else => "`else`",
+            Self::While => "`while`",
+            Self::For => "`for`",
+            Self::Return => "`return`",
+            Self::Break => "`break`",
+            Self::Continue => "`continue`",
+            Self::Struct => "`struct`",
+            Self::Enum => "`enum`",
+            Self::Pub => "`pub`",
+            Self::Crate => "`crate`",
+            Self::Super => "`super`",
+            Self::True => "`true`",
+            Self::False => "`false`",
+
+            Self::OpenParen => "open parenthesis",
+            Self::CloseParen => "close parenthesis",
+            Self::OpenBrace => "open brace",
+            Self::CloseBrace => "close brace",
+            Self::OpenBracket => "open bracket",
+            Self::CloseBracket => "close bracket",
+
+            Self::Hash => "`#`",
+            Self::Dot => "`.`",
+            Self::Comma => "`,`",
+            Self::Colon => "`:`",
+            Self::ColonColon => "`::`",
+            Self::Semicolon => "`;`",
+
+            Self::Arrow => "`->`",
+
+            Self::Equals => "`=`",
+            Self::Not => "`!`",
+
+            Self::EqualEquals => "`==`",
+            Self::NotEquals => "`!=`",
+            Self::LessThan => "`<`",
+            Self::GreaterThan => "`>`",
+            Self::LessThanEquals => "`<=`",
+            Self::GreaterThanEquals => "`>=`",
+
+            Self::Plus => "`+`",
+            Self::Minus => "`-`",
+            Self::Star => "`*`",
+            Self::Slash => "`/`",
+            Self::Percent => "`%`",
+
+            Self::PlusEquals => "`+=`",
+            Self::MinusEquals => "`-=`",
+            Self::StarEquals => "`*=`",
+            Self::SlashEquals => "`/=`",
+            Self::PercentEquals => "`%=`",
+
+            Self::Amp => "`&`",
+            Self::Pipe => "`|`",
+            Self::Caret => "`^`",
+            Self::AndAnd => "`&&`",
+            Self::OrOr => "`||`",
+        }
+    }
+
+    pub fn is_trivia(self) -> bool {
+        matches!(
+            self,
+            Self::Whitespace { .. } | Self::BlockComment { .. } | Self::LineComment | Self::Shebang
+        )
+    }
+
+    /// Whether this is a keyword. Note that `true` and `false` are keywords, as
+    /// they are for `SyntaxKind::is_keyword`.
+    pub fn is_keyword(self) -> bool {
+        matches!(
+            self,
+            Self::Fn
+                | Self::Let
+                | Self::Mut
+                | Self::If
+                | Self::Else
+                | Self::While
+                | Self::For
+                | Self::Return
+                | Self::Break
+                | Self::Continue
+                | Self::Struct
+                | Self::Enum
+                | Self::Pub
+                | Self::Crate
+                | Self::Super
+                | Self::True
+                | Self::False
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Base {
+    Binary,
+    Octal,
+    Decimal,
+    Hexadecimal,
+}
+
+impl Base {
+    pub fn radix(self) -> u32 {
+        match self {
+            Self::Binary => 2,
+            Self::Octal => 8,
+            Self::Decimal => 10,
+            Self::Hexadecimal => 16,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_trivia() {
+        let trivia = [
+            TokenKind::Whitespace { newlines: 0 },
+            TokenKind::LineComment,
+            TokenKind::Shebang,
+            TokenKind::BlockComment {
+                is_terminated: true,
+            },
+            TokenKind::BlockComment {
+                is_terminated: false,
+            },
+        ];
+
+        let significant = [
+            TokenKind::Unknown,
+            TokenKind::String {
+                is_terminated: true,
+                has_invalid_escape: false,
+            },
+            TokenKind::Char {
+                is_terminated: true,
+            },
+            TokenKind::ByteString {
+                is_terminated: true,
+                has_invalid_escape: false,
+                has_non_ascii: false,
+            },
+            TokenKind::Byte {
+                is_terminated: true,
+                has_non_ascii: false,
+            },
+            TokenKind::MultilineString {
+                is_terminated: true,
+                indent: 0,
+            },
+            TokenKind::StringFragment,
+            TokenKind::InterpStart,
+            TokenKind::InterpEnd,
+            TokenKind::Ident,
+            TokenKind::Int {
+                base: Base::Decimal,
+                is_empty: false,
+            },
+            TokenKind::Float,
+            TokenKind::Fn,
+            TokenKind::Let,
+            TokenKind::Mut,
+            TokenKind::If,
+            TokenKind::Else,
+            TokenKind::While,
+            TokenKind::For,
+            TokenKind::Return,
+            TokenKind::Break,
+            TokenKind::Continue,
+            TokenKind::Struct,
+            TokenKind::Enum,
+            TokenKind::Pub,
+            TokenKind::Crate,
+            TokenKind::Super,
+            TokenKind::True,
+            TokenKind::False,
+            TokenKind::OpenParen,
+            TokenKind::CloseParen,
+            TokenKind::OpenBrace,
+            TokenKind::CloseBrace,
+            TokenKind::OpenBracket,
+            TokenKind::CloseBracket,
+            TokenKind::Hash,
+            TokenKind::Dot,
+            TokenKind::Comma,
+            TokenKind::Colon,
+            TokenKind::ColonColon,
+            TokenKind::Semicolon,
+            TokenKind::Arrow,
+            TokenKind::Equals,
+            TokenKind::Not,
+            TokenKind::EqualEquals,
+            TokenKind::NotEquals,
+            TokenKind::LessThan,
+            TokenKind::GreaterThan,
+            TokenKind::LessThanEquals,
+            TokenKind::GreaterThanEquals,
+            TokenKind::Plus,
+            TokenKind::Minus,
+            TokenKind::Star,
+            TokenKind::Slash,
+            TokenKind::Percent,
+            TokenKind::PlusEquals,
+            TokenKind::MinusEquals,
+            TokenKind::StarEquals,
+            TokenKind::SlashEquals,
+            TokenKind::PercentEquals,
+            TokenKind::Amp,
+            TokenKind::Pipe,
+            TokenKind::Caret,
+            TokenKind::AndAnd,
+            TokenKind::OrOr,
+        ];
+
+        for kind in trivia {
+            assert!(kind.is_trivia(), "{kind:?} should be trivia");
+        }
+
+        for kind in significant {
+            assert!(!kind.is_trivia(), "{kind:?} should not be trivia");
+        }
+    }
+
+    #[test]
+    fn test_description() {
+        assert_eq!(
+            TokenKind::String {
+                is_terminated: true,
+                has_invalid_escape: false
+            }
+            .description(),
+            "string literal"
+        );
+        assert_eq!(TokenKind::OpenBrace.description(), "open brace");
+        assert_eq!(TokenKind::Ident.description(), "identifier");
+        assert_eq!(TokenKind::Fn.description(), "`fn`");
+        assert_eq!(TokenKind::Arrow.description(), "`->`");
+    }
+
+    #[test]
+    fn test_is_keyword() {
+        let keywords = [
+            "fn", "let", "mut", "if", "else", "while", "for", "return", "break", "continue",
+            "struct", "enum", "pub", "crate", "super", "true", "false",
+        ];
+
+        for keyword in keywords {
+            let kind = crate::Lexer::new(keyword).next().unwrap().kind();
+            assert!(kind.is_keyword(), "{keyword} should be a keyword");
+        }
+
+        assert!(!TokenKind::Ident.is_keyword());
+        assert!(!TokenKind::Arrow.is_keyword());
+    }
 }
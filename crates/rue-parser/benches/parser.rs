@@ -0,0 +1,68 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use rue_parser::{parse_text, parse_text_with_errors};
+
+/// Counts the bytes allocated, so that the benchmark can report the memory used
+/// by a single parse alongside the time it takes.
+struct CountingAlloc;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Generates a large program out of many small functions, using most of the syntax.
+fn generate(functions: usize) -> String {
+    let mut source = String::new();
+
+    for i in 0..functions {
+        source.push_str(&format!(
+            "// Function number {i}.\n\
+             fn function_{i}(a: Int, b: Int) -> Int {{\n\
+             \x20   let total = a * {i} + b;\n\
+             \x20   while total < 0x{i:x} {{\n\
+             \x20       function_{i}(total, 1);\n\
+             \x20   }}\n\
+             \x20   if total >= 10 && !false {{ total - 1 }} else {{ function_{i}(total, 2) }}\n\
+             }}\n\n"
+        ));
+    }
+
+    source
+}
+
+fn parse_large(c: &mut Criterion) {
+    let source = generate(5000);
+    assert_eq!(parse_text_with_errors(&source).errors, []);
+
+    let before = ALLOCATED.load(Ordering::Relaxed);
+    drop(parse_text(&source));
+    let allocated = ALLOCATED.load(Ordering::Relaxed) - before;
+    println!(
+        "parser/generated: {allocated} bytes allocated for {} bytes of source",
+        source.len()
+    );
+
+    let mut group = c.benchmark_group("parser");
+    group.throughput(Throughput::Bytes(source.len() as u64));
+    group.bench_function("generated", |b| b.iter(|| parse_text(black_box(&source))));
+    group.finish();
+}
+
+criterion_group!(benches, parse_large);
+criterion_main!(benches);
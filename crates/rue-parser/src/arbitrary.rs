@@ -0,0 +1,273 @@
+//! A simplified syntax tree which can be generated with the `arbitrary` crate and
+//! rendered back to source, for structure-aware fuzzing. Everything it renders is
+//! valid syntax, so it should always parse without errors.
+
+use std::fmt::Write;
+
+use arbitrary::Arbitrary;
+
+#[derive(Debug, Clone, Arbitrary)]
+pub struct Program {
+    pub functions: Vec<Function>,
+}
+
+#[derive(Debug, Clone, Arbitrary)]
+pub struct Function {
+    pub name: Name,
+    pub params: Vec<(Name, Type)>,
+    pub ret: Option<Type>,
+    pub body: Block,
+}
+
+#[derive(Debug, Clone, Arbitrary)]
+pub enum Type {
+    Int,
+    Bool,
+    Path(Name, Name),
+    Tuple(Vec<Type>),
+}
+
+#[derive(Debug, Clone, Arbitrary)]
+pub struct Block {
+    pub stmts: Vec<Stmt>,
+    pub tail: Option<Box<Expr>>,
+}
+
+#[derive(Debug, Clone, Arbitrary)]
+pub enum Stmt {
+    Let(Name, Option<Type>, Expr),
+    Expr(Expr),
+}
+
+#[derive(Debug, Clone, Arbitrary)]
+pub enum Expr {
+    Int(u32),
+    Bool(bool),
+    Name(Name),
+    Paren(Box<Expr>),
+    Prefix(PrefixOp, Box<Expr>),
+    Binary(Box<Expr>, BinaryOp, Box<Expr>),
+    Call(Name, Vec<Expr>),
+    Field(Box<Expr>, Name),
+    If(Box<Expr>, Block, Option<Block>),
+    While(Box<Expr>, Block),
+}
+
+#[derive(Debug, Clone, Copy, Arbitrary)]
+pub enum PrefixOp {
+    Neg,
+    Not,
+}
+
+#[derive(Debug, Clone, Copy, Arbitrary)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Eq,
+    NotEq,
+    Lt,
+    Gt,
+    LtEq,
+    GtEq,
+    And,
+    Or,
+    BitAnd,
+    BitOr,
+    BitXor,
+}
+
+/// Names are numbered, so that they can never clash with a keyword.
+#[derive(Debug, Clone, Copy, Arbitrary)]
+pub struct Name(pub u8);
+
+pub fn render(program: &Program) -> String {
+    let mut output = String::new();
+    for function in &program.functions {
+        render_function(&mut output, function);
+        output.push('\n');
+    }
+    output
+}
+
+fn render_function(output: &mut String, function: &Function) {
+    write!(output, "fn {}(", function.name).unwrap();
+    for (i, (name, ty)) in function.params.iter().enumerate() {
+        if i > 0 {
+            output.push_str(", ");
+        }
+        write!(output, "{name}: ").unwrap();
+        render_type(output, ty);
+    }
+    output.push_str(") ");
+
+    if let Some(ty) = &function.ret {
+        output.push_str("-> ");
+        render_type(output, ty);
+        output.push(' ');
+    }
+
+    render_block(output, &function.body);
+}
+
+fn render_type(output: &mut String, ty: &Type) {
+    match ty {
+        Type::Int => output.push_str("Int"),
+        Type::Bool => output.push_str("Bool"),
+        Type::Path(module, name) => write!(output, "{module}::{name}").unwrap(),
+        Type::Tuple(types) => {
+            output.push('(');
+            for (i, ty) in types.iter().enumerate() {
+                if i > 0 {
+                    output.push_str(", ");
+                }
+                render_type(output, ty);
+            }
+            if types.len() == 1 {
+                output.push(',');
+            }
+            output.push(')');
+        }
+    }
+}
+
+fn render_block(output: &mut String, block: &Block) {
+    output.push('{');
+
+    for stmt in &block.stmts {
+        output.push(' ');
+        match stmt {
+            Stmt::Let(name, ty, expr) => {
+                write!(output, "let {name}").unwrap();
+                if let Some(ty) = ty {
+                    output.push_str(": ");
+                    render_type(output, ty);
+                }
+                output.push_str(" = ");
+                render_expr(output, expr);
+            }
+            Stmt::Expr(expr) => render_stmt_expr(output, expr),
+        }
+        output.push(';');
+    }
+
+    if let Some(expr) = &block.tail {
+        output.push(' ');
+        render_stmt_expr(output, expr);
+    }
+
+    output.push_str(" }");
+}
+
+/// An expression starting with `if` or `while` would end at its block when used as
+/// a statement, so it's wrapped in parentheses to keep any operators after it.
+fn render_stmt_expr(output: &mut String, expr: &Expr) {
+    if starts_with_block(expr) {
+        output.push('(');
+        render_expr(output, expr);
+        output.push(')');
+    } else {
+        render_expr(output, expr);
+    }
+}
+
+fn starts_with_block(expr: &Expr) -> bool {
+    match expr {
+        Expr::If(..) | Expr::While(..) => true,
+        Expr::Binary(lhs, _, _) | Expr::Field(lhs, _) => starts_with_block(lhs),
+        _ => false,
+    }
+}
+
+fn render_expr(output: &mut String, expr: &Expr) {
+    match expr {
+        Expr::Int(value) => write!(output, "{value}").unwrap(),
+        Expr::Bool(value) => write!(output, "{value}").unwrap(),
+        Expr::Name(name) => write!(output, "{name}").unwrap(),
+        Expr::Paren(expr) => {
+            output.push('(');
+            render_expr(output, expr);
+            output.push(')');
+        }
+        Expr::Prefix(op, expr) => {
+            output.push(match op {
+                PrefixOp::Neg => '-',
+                PrefixOp::Not => '!',
+            });
+            render_expr(output, expr);
+        }
+        Expr::Binary(lhs, op, rhs) => {
+            render_expr(output, lhs);
+            write!(output, " {} ", op.text()).unwrap();
+            render_expr(output, rhs);
+        }
+        Expr::Call(name, args) => {
+            write!(output, "{name}(").unwrap();
+            for (i, arg) in args.iter().enumerate() {
+                if i > 0 {
+                    output.push_str(", ");
+                }
+                render_expr(output, arg);
+            }
+            output.push(')');
+        }
+        Expr::Field(expr, name) => {
+            // A dot directly after an integer would be lexed as part of a float.
+            if matches!(**expr, Expr::Int(_)) {
+                output.push('(');
+                render_expr(output, expr);
+                output.push(')');
+            } else {
+                render_expr(output, expr);
+            }
+            write!(output, ".{name}").unwrap();
+        }
+        Expr::If(condition, then_branch, else_branch) => {
+            output.push_str("if ");
+            render_expr(output, condition);
+            output.push(' ');
+            render_block(output, then_branch);
+            if let Some(else_branch) = else_branch {
+                output.push_str(" else ");
+                render_block(output, else_branch);
+            }
+        }
+        Expr::While(condition, body) => {
+            output.push_str("while ");
+            render_expr(output, condition);
+            output.push(' ');
+            render_block(output, body);
+        }
+    }
+}
+
+impl BinaryOp {
+    fn text(self) -> &'static str {
+        match self {
+            Self::Add => "+",
+            Self::Sub => "-",
+            Self::Mul => "*",
+            Self::Div => "/",
+            Self::Rem => "%",
+            Self::Eq => "==",
+            Self::NotEq => "!=",
+            Self::Lt => "<",
+            Self::Gt => ">",
+            Self::LtEq => "<=",
+            Self::GtEq => ">=",
+            Self::And => "&&",
+            Self::Or => "||",
+            Self::BitAnd => "&",
+            Self::BitOr => "|",
+            Self::BitXor => "^",
+        }
+    }
+}
+
+impl std::fmt::Display for Name {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "x{}", self.0)
+    }
+}
@@ -0,0 +1,393 @@
+use rue_syntax::SyntaxKind;
+
+use crate::{
+    expr::{at_expr, parse_expr, parse_if, parse_while},
+    parser::Parser,
+    ty::parse_type,
+};
+
+const BLOCK_END: &[SyntaxKind] = &[SyntaxKind::CloseBrace, SyntaxKind::Fn];
+
+pub fn parse_block(p: &mut Parser) {
+    p.start(SyntaxKind::Block);
+    p.expect_open(SyntaxKind::OpenBrace, &[SyntaxKind::Fn]);
+
+    while !p.at_eof() && !p.at_any(BLOCK_END) {
+        parse_stmt(p);
+    }
+
+    p.expect_close(SyntaxKind::CloseBrace, &[SyntaxKind::Fn]);
+    p.finish();
+}
+
+fn parse_stmt(p: &mut Parser) {
+    if p.at(SyntaxKind::Let) {
+        parse_let(p);
+        return;
+    }
+
+    if p.at_any(&[SyntaxKind::If, SyntaxKind::While]) {
+        parse_block_like_stmt(p);
+        return;
+    }
+
+    if !at_expr(p) {
+        p.error("expected statement".to_string());
+        return;
+    }
+
+    let checkpoint = p.checkpoint();
+    parse_expr(p);
+
+    // An expression at the end of the block is its value, rather than a statement.
+    if p.at_eof() || p.at_any(BLOCK_END) {
+        return;
+    }
+
+    p.start_at(checkpoint, SyntaxKind::ExprStmt);
+    p.expect_recover(SyntaxKind::Semicolon, BLOCK_END);
+    p.finish();
+}
+
+/// Block-like expressions can be used as statements without a trailing semicolon.
+fn parse_block_like_stmt(p: &mut Parser) {
+    let checkpoint = p.checkpoint();
+
+    if p.at(SyntaxKind::If) {
+        parse_if(p);
+    } else {
+        parse_while(p);
+    }
+
+    if p.at_eof() || p.at_any(BLOCK_END) {
+        return;
+    }
+
+    p.start_at(checkpoint, SyntaxKind::ExprStmt);
+    p.eat(SyntaxKind::Semicolon);
+    p.finish();
+}
+
+pub fn parse_let(p: &mut Parser) {
+    p.start(SyntaxKind::LetStmt);
+    p.expect(SyntaxKind::Let);
+    p.expect_recover(
+        SyntaxKind::Ident,
+        &[
+            SyntaxKind::Colon,
+            SyntaxKind::Equals,
+            SyntaxKind::Semicolon,
+            SyntaxKind::CloseBrace,
+        ],
+    );
+
+    if p.eat(SyntaxKind::Colon) {
+        parse_type(p);
+    }
+
+    if p.eat(SyntaxKind::Equals) {
+        parse_expr(p);
+    } else if at_expr(p) {
+        p.missing("expected `=`".to_string());
+        parse_expr(p);
+    }
+
+    p.expect_recover(
+        SyntaxKind::Semicolon,
+        &[SyntaxKind::Let, SyntaxKind::CloseBrace, SyntaxKind::Fn],
+    );
+    p.finish();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use expect_test::{expect, Expect};
+    use rue_lexer::{Lexer, Token};
+
+    crate::check!(check_block, parse_block);
+    crate::check!(check_let, parse_let);
+
+    #[test]
+    fn parse_empty_block() {
+        check_block(
+            "{}",
+            expect![[r#"
+                Block@0..2
+                  OpenBrace@0..1 "{"
+                  CloseBrace@1..2 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_tail_expr() {
+        check_block(
+            "{ x }",
+            expect![[r#"
+                Block@0..5
+                  OpenBrace@0..1 "{"
+                  Whitespace@1..2 " "
+                  NameRef@2..4
+                    Ident@2..3 "x"
+                    Whitespace@3..4 " "
+                  CloseBrace@4..5 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_expr_stmts() {
+        check_block(
+            "{ a; b + 1; c }",
+            expect![[r#"
+                Block@0..15
+                  OpenBrace@0..1 "{"
+                  Whitespace@1..2 " "
+                  ExprStmt@2..5
+                    NameRef@2..3
+                      Ident@2..3 "a"
+                    Semicolon@3..4 ";"
+                    Whitespace@4..5 " "
+                  ExprStmt@5..12
+                    BinaryExpr@5..10
+                      NameRef@5..7
+                        Ident@5..6 "b"
+                        Whitespace@6..7 " "
+                      Plus@7..8 "+"
+                      Whitespace@8..9 " "
+                      Literal@9..10
+                        Int@9..10 "1"
+                    Semicolon@10..11 ";"
+                    Whitespace@11..12 " "
+                  NameRef@12..14
+                    Ident@12..13 "c"
+                    Whitespace@13..14 " "
+                  CloseBrace@14..15 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_unterminated_block() {
+        check_block(
+            "{ x;\nfn main() {}",
+            expect![[r#"
+                Block@0..5
+                  OpenBrace@0..1 "{"
+                  Whitespace@1..2 " "
+                  ExprStmt@2..5
+                    NameRef@2..3
+                      Ident@2..3 "x"
+                    Semicolon@3..4 ";"
+                    Whitespace@4..5 "\n""#]],
+        );
+    }
+
+    #[test]
+    fn parse_let_stmt() {
+        check_let(
+            "let x = 1;",
+            expect![[r#"
+                LetStmt@0..10
+                  Let@0..3 "let"
+                  Whitespace@3..4 " "
+                  Ident@4..5 "x"
+                  Whitespace@5..6 " "
+                  Equals@6..7 "="
+                  Whitespace@7..8 " "
+                  Literal@8..9
+                    Int@8..9 "1"
+                  Semicolon@9..10 ";""#]],
+        );
+    }
+
+    #[test]
+    fn parse_let_with_type() {
+        check_let(
+            "let x: Int = 1;",
+            expect![[r#"
+                LetStmt@0..15
+                  Let@0..3 "let"
+                  Whitespace@3..4 " "
+                  Ident@4..5 "x"
+                  Colon@5..6 ":"
+                  Whitespace@6..7 " "
+                  PathType@7..11
+                    Ident@7..10 "Int"
+                    Whitespace@10..11 " "
+                  Equals@11..12 "="
+                  Whitespace@12..13 " "
+                  Literal@13..14
+                    Int@13..14 "1"
+                  Semicolon@14..15 ";""#]],
+        );
+    }
+
+    #[test]
+    fn parse_let_without_initializer() {
+        check_let(
+            "let x;",
+            expect![[r#"
+                LetStmt@0..6
+                  Let@0..3 "let"
+                  Whitespace@3..4 " "
+                  Ident@4..5 "x"
+                  Semicolon@5..6 ";""#]],
+        );
+    }
+
+    #[test]
+    fn parse_let_missing_name() {
+        check_let(
+            "let = 1;",
+            expect![[r#"
+                LetStmt@0..8
+                  Let@0..3 "let"
+                  Whitespace@3..4 " "
+                  Equals@4..5 "="
+                  Whitespace@5..6 " "
+                  Literal@6..7
+                    Int@6..7 "1"
+                  Semicolon@7..8 ";""#]],
+        );
+    }
+
+    #[test]
+    fn parse_let_missing_equals_and_semicolon() {
+        check_block(
+            "{ let x 1 let y = 2; y }",
+            expect![[r#"
+                Block@0..24
+                  OpenBrace@0..1 "{"
+                  Whitespace@1..2 " "
+                  LetStmt@2..10
+                    Let@2..5 "let"
+                    Whitespace@5..6 " "
+                    Ident@6..7 "x"
+                    Whitespace@7..8 " "
+                    ErrorNode@8..8
+                    Literal@8..10
+                      Int@8..9 "1"
+                      Whitespace@9..10 " "
+                  LetStmt@10..21
+                    Let@10..13 "let"
+                    Whitespace@13..14 " "
+                    Ident@14..15 "y"
+                    Whitespace@15..16 " "
+                    Equals@16..17 "="
+                    Whitespace@17..18 " "
+                    Literal@18..19
+                      Int@18..19 "2"
+                    Semicolon@19..20 ";"
+                    Whitespace@20..21 " "
+                  NameRef@21..23
+                    Ident@21..22 "y"
+                    Whitespace@22..23 " "
+                  CloseBrace@23..24 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_if_stmt() {
+        check_block(
+            "{ if a {} b }",
+            expect![[r#"
+                Block@0..13
+                  OpenBrace@0..1 "{"
+                  Whitespace@1..2 " "
+                  ExprStmt@2..10
+                    IfExpr@2..10
+                      If@2..4 "if"
+                      Whitespace@4..5 " "
+                      NameRef@5..7
+                        Ident@5..6 "a"
+                        Whitespace@6..7 " "
+                      Block@7..10
+                        OpenBrace@7..8 "{"
+                        CloseBrace@8..9 "}"
+                        Whitespace@9..10 " "
+                  NameRef@10..12
+                    Ident@10..11 "b"
+                    Whitespace@11..12 " "
+                  CloseBrace@12..13 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_stmt_comments() {
+        check_block(
+            "{\n    // The answer.\n    let x = 42; // Trailing.\n    x\n    // End.\n}",
+            expect![[r#"
+                Block@0..69
+                  OpenBrace@0..1 "{"
+                  Whitespace@1..6 "\n    "
+                  LetStmt@6..54
+                    LineComment@6..20 "// The answer."
+                    Whitespace@20..25 "\n    "
+                    Let@25..28 "let"
+                    Whitespace@28..29 " "
+                    Ident@29..30 "x"
+                    Whitespace@30..31 " "
+                    Equals@31..32 "="
+                    Whitespace@32..33 " "
+                    Literal@33..35
+                      Int@33..35 "42"
+                    Semicolon@35..36 ";"
+                    Whitespace@36..37 " "
+                    LineComment@37..49 "// Trailing."
+                    Whitespace@49..54 "\n    "
+                  NameRef@54..60
+                    Ident@54..55 "x"
+                    Whitespace@55..60 "\n    "
+                  LineComment@60..67 "// End."
+                  Whitespace@67..68 "\n"
+                  CloseBrace@68..69 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_return_stmts() {
+        check_block(
+            "{ return; return 1; }",
+            expect![[r#"
+                Block@0..21
+                  OpenBrace@0..1 "{"
+                  Whitespace@1..2 " "
+                  ExprStmt@2..10
+                    ReturnExpr@2..8
+                      Return@2..8 "return"
+                    Semicolon@8..9 ";"
+                    Whitespace@9..10 " "
+                  ExprStmt@10..20
+                    ReturnExpr@10..18
+                      Return@10..16 "return"
+                      Whitespace@16..17 " "
+                      Literal@17..18
+                        Int@17..18 "1"
+                    Semicolon@18..19 ";"
+                    Whitespace@19..20 " "
+                  CloseBrace@20..21 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_break_continue() {
+        check_block(
+            "{ break; continue; }",
+            expect![[r#"
+                Block@0..20
+                  OpenBrace@0..1 "{"
+                  Whitespace@1..2 " "
+                  ExprStmt@2..9
+                    BreakExpr@2..7
+                      Break@2..7 "break"
+                    Semicolon@7..8 ";"
+                    Whitespace@8..9 " "
+                  ExprStmt@9..19
+                    ContinueExpr@9..17
+                      Continue@9..17 "continue"
+                    Semicolon@17..18 ";"
+                    Whitespace@18..19 " "
+                  CloseBrace@19..20 "}""#]],
+        );
+    }
+}
@@ -0,0 +1,835 @@
+use rowan::Checkpoint;
+use rue_syntax::SyntaxKind;
+
+use crate::{block::parse_block, parser::Parser};
+
+const LITERALS: &[SyntaxKind] = &[
+    SyntaxKind::Int,
+    SyntaxKind::Float,
+    SyntaxKind::String,
+    SyntaxKind::Char,
+    SyntaxKind::ByteString,
+    SyntaxKind::Byte,
+    SyntaxKind::True,
+    SyntaxKind::False,
+];
+
+const PREFIX_OPS: &[SyntaxKind] = &[SyntaxKind::Minus, SyntaxKind::Not];
+
+/// Binary operators and their binding powers. Composite operators such as `==`
+/// are listed before their first part, so that they take precedence.
+const BINARY_OPS: &[(SyntaxKind, u8)] = &[
+    (SyntaxKind::OrOr, 1),
+    (SyntaxKind::AndAnd, 2),
+    (SyntaxKind::EqualEquals, 3),
+    (SyntaxKind::NotEquals, 3),
+    (SyntaxKind::LessThanEquals, 3),
+    (SyntaxKind::GreaterThanEquals, 3),
+    (SyntaxKind::LessThan, 3),
+    (SyntaxKind::GreaterThan, 3),
+    (SyntaxKind::Pipe, 4),
+    (SyntaxKind::Caret, 5),
+    (SyntaxKind::Amp, 6),
+    (SyntaxKind::Plus, 7),
+    (SyntaxKind::Minus, 7),
+    (SyntaxKind::Star, 8),
+    (SyntaxKind::Slash, 8),
+    (SyntaxKind::Percent, 8),
+];
+
+const PREFIX_BINDING_POWER: u8 = 9;
+
+const EXPR_RECOVERY: &[SyntaxKind] = &[
+    SyntaxKind::CloseParen,
+    SyntaxKind::CloseBrace,
+    SyntaxKind::Semicolon,
+    SyntaxKind::Fn,
+];
+
+pub fn at_expr(p: &mut Parser) -> bool {
+    p.at_any(LITERALS)
+        || p.at_any(PREFIX_OPS)
+        || p.at_any(&[
+            SyntaxKind::StringFragment,
+            SyntaxKind::Ident,
+            SyntaxKind::OpenParen,
+            SyntaxKind::If,
+            SyntaxKind::While,
+            SyntaxKind::Return,
+            SyntaxKind::Break,
+            SyntaxKind::Continue,
+        ])
+}
+
+pub fn parse_expr(p: &mut Parser) {
+    parse_expr_bp(p, 0);
+}
+
+fn parse_expr_bp(p: &mut Parser, min_binding_power: u8) {
+    let checkpoint = p.checkpoint();
+
+    if let Some(&op) = PREFIX_OPS.iter().find(|&&kind| p.at(kind)) {
+        p.start(SyntaxKind::PrefixExpr);
+        p.expect(op);
+        parse_expr_bp(p, PREFIX_BINDING_POWER);
+        p.finish();
+    } else {
+        parse_primary_expr(p);
+
+        // Postfix operators bind tighter than any prefix or binary operator.
+        loop {
+            if p.at(SyntaxKind::OpenParen) {
+                p.start_at(checkpoint, SyntaxKind::CallExpr);
+                parse_args(p);
+                p.finish();
+            } else if p.at(SyntaxKind::Dot) {
+                parse_field(p, checkpoint);
+            } else {
+                break;
+            }
+        }
+    }
+
+    while let Some(&(op, binding_power)) = BINARY_OPS.iter().find(|op| p.at(op.0)) {
+        if binding_power <= min_binding_power {
+            break;
+        }
+
+        p.start_at(checkpoint, SyntaxKind::BinaryExpr);
+        p.expect(op);
+        parse_expr_bp(p, binding_power);
+        p.finish();
+    }
+}
+
+fn parse_primary_expr(p: &mut Parser) {
+    if let Some(&kind) = LITERALS.iter().find(|&&kind| p.at(kind)) {
+        p.start(SyntaxKind::Literal);
+        p.expect(kind);
+        p.finish();
+    } else if p.at(SyntaxKind::StringFragment) {
+        parse_interp_string(p);
+    } else if p.at(SyntaxKind::Ident) {
+        p.start(SyntaxKind::NameRef);
+        p.expect(SyntaxKind::Ident);
+        p.finish();
+    } else if p.at(SyntaxKind::OpenParen) {
+        p.start(SyntaxKind::ParenExpr);
+        p.expect(SyntaxKind::OpenParen);
+        parse_expr(p);
+        p.expect_recover(
+            SyntaxKind::CloseParen,
+            &[SyntaxKind::CloseBrace, SyntaxKind::Semicolon],
+        );
+        p.finish();
+    } else if p.at(SyntaxKind::If) {
+        parse_if(p);
+    } else if p.at(SyntaxKind::While) {
+        parse_while(p);
+    } else if p.at(SyntaxKind::Return) {
+        parse_jump(p, SyntaxKind::ReturnExpr, SyntaxKind::Return);
+    } else if p.at(SyntaxKind::Break) {
+        parse_jump(p, SyntaxKind::BreakExpr, SyntaxKind::Break);
+    } else if p.at(SyntaxKind::Continue) {
+        p.start(SyntaxKind::ContinueExpr);
+        p.expect(SyntaxKind::Continue);
+        p.finish();
+    } else if p.at_eof() || p.at_any(EXPR_RECOVERY) {
+        p.missing("expected expression".to_string());
+    } else {
+        p.error("expected expression".to_string());
+    }
+}
+
+/// Parses a string with interpolations, such as `"a ${b} c"`. The lexer splits it into
+/// fragments around each interpolated expression.
+fn parse_interp_string(p: &mut Parser) {
+    p.start(SyntaxKind::InterpString);
+    p.expect(SyntaxKind::StringFragment);
+
+    while p.at(SyntaxKind::InterpStart) {
+        p.expect(SyntaxKind::InterpStart);
+        parse_expr(p);
+        p.expect_recover(SyntaxKind::InterpEnd, EXPR_RECOVERY);
+        p.expect_recover(SyntaxKind::StringFragment, EXPR_RECOVERY);
+    }
+
+    p.finish();
+}
+
+/// Parses `return` or `break`, which take an optional value. The value extends as
+/// far as possible, so `return a + b` returns the sum.
+fn parse_jump(p: &mut Parser, node: SyntaxKind, keyword: SyntaxKind) {
+    p.start(node);
+    p.expect(keyword);
+    if at_expr(p) {
+        parse_expr(p);
+    }
+    p.finish();
+}
+
+/// Parses a field access such as `a.b` or `a.0`, or a method call such as `a.b()`
+/// if the field is followed by arguments.
+fn parse_field(p: &mut Parser, checkpoint: Checkpoint) {
+    p.expect(SyntaxKind::Dot);
+
+    if let Some(index) = split_tuple_indices(p) {
+        // `a.0.1` lexes its indices as the float `0.1`, so it's split back into
+        // `0`, `.` and `1`, the first of which is the inner field access.
+        p.bump_split(SyntaxKind::Int, index, SyntaxKind::Dot);
+        p.start_at(checkpoint, SyntaxKind::FieldExpr);
+        p.finish();
+        p.bump_split(SyntaxKind::Dot, 1, SyntaxKind::Int);
+        p.expect(SyntaxKind::Int);
+    } else if p.at(SyntaxKind::Int) {
+        p.expect(SyntaxKind::Int);
+    } else {
+        p.expect_recover(SyntaxKind::Ident, EXPR_RECOVERY);
+    }
+
+    if p.at(SyntaxKind::OpenParen) {
+        p.start_at(checkpoint, SyntaxKind::MethodCallExpr);
+        parse_args(p);
+    } else {
+        p.start_at(checkpoint, SyntaxKind::FieldExpr);
+    }
+
+    p.finish();
+}
+
+/// The length of the first index, if the next token is a float such as `0.1` which is
+/// really two tuple indices.
+fn split_tuple_indices(p: &mut Parser) -> Option<usize> {
+    if !p.at(SyntaxKind::Float) {
+        return None;
+    }
+    let (first, second) = p.peek_text().split_once('.')?;
+    let is_index = |text: &str| !text.is_empty() && text.bytes().all(|b| b.is_ascii_digit());
+    (is_index(first) && is_index(second)).then_some(first.len())
+}
+
+pub fn parse_args(p: &mut Parser) {
+    p.start(SyntaxKind::ArgList);
+    p.expect(SyntaxKind::OpenParen);
+
+    // A missing closing parenthesis ends the arguments at the end of the statement.
+    while !p.at_eof() && !p.at_any(EXPR_RECOVERY) {
+        parse_expr(p);
+        if !p.at(SyntaxKind::CloseParen) {
+            p.expect_recover(SyntaxKind::Comma, EXPR_RECOVERY);
+        }
+    }
+
+    p.expect_recover(SyntaxKind::CloseParen, EXPR_RECOVERY);
+    p.finish();
+}
+
+pub fn parse_if(p: &mut Parser) {
+    p.start(SyntaxKind::IfExpr);
+    p.expect(SyntaxKind::If);
+
+    parse_condition(p);
+    parse_branch(p);
+
+    if p.eat(SyntaxKind::Else) {
+        if p.at(SyntaxKind::If) {
+            parse_if(p);
+        } else if !p.at(SyntaxKind::OpenBrace) && at_expr(p) {
+            // Like Rust, an `else` is followed by a block or another `if`, but the
+            // expression is still parsed so that the error doesn't cascade.
+            p.error_with(
+                "expected block or `if` after `else`, wrap the expression in braces".to_string(),
+                parse_expr,
+            );
+        } else {
+            parse_branch(p);
+        }
+    }
+
+    p.finish();
+}
+
+pub fn parse_while(p: &mut Parser) {
+    p.start(SyntaxKind::WhileExpr);
+    p.expect(SyntaxKind::While);
+    parse_condition(p);
+    parse_branch(p);
+    p.finish();
+}
+
+fn parse_condition(p: &mut Parser) {
+    if p.at(SyntaxKind::OpenBrace) {
+        p.missing("expected condition".to_string());
+    } else {
+        parse_expr(p);
+    }
+}
+
+fn parse_branch(p: &mut Parser) {
+    if p.at(SyntaxKind::OpenBrace) {
+        parse_block(p);
+    } else {
+        p.missing("expected block".to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use expect_test::{expect, Expect};
+    use rowan::TextRange;
+    use rue_lexer::{Lexer, Token};
+    use rue_syntax::ast::{AstNode, BinaryExpr, Expr, LiteralKind};
+
+    use crate::ParseError;
+
+    crate::check!(check_expr, parse_expr);
+
+    #[test]
+    fn parse_literals() {
+        check_expr(
+            "42",
+            expect![[r#"
+                Literal@0..2
+                  Int@0..2 "42""#]],
+        );
+        check_expr(
+            "true",
+            expect![[r#"
+                Literal@0..4
+                  True@0..4 "true""#]],
+        );
+        check_expr(
+            "\"hello\"",
+            expect![[r#"
+                Literal@0..7
+                  String@0..7 "\"hello\"""#]],
+        );
+        check_expr(
+            "b'x'",
+            expect![[r#"
+                Literal@0..4
+                  Byte@0..4 "b'x'""#]],
+        );
+    }
+
+    #[test]
+    fn parse_precedence() {
+        check_expr(
+            "1 + 2 * 3",
+            expect![[r#"
+                BinaryExpr@0..9
+                  Literal@0..2
+                    Int@0..1 "1"
+                    Whitespace@1..2 " "
+                  Plus@2..3 "+"
+                  Whitespace@3..4 " "
+                  BinaryExpr@4..9
+                    Literal@4..6
+                      Int@4..5 "2"
+                      Whitespace@5..6 " "
+                    Star@6..7 "*"
+                    Whitespace@7..8 " "
+                    Literal@8..9
+                      Int@8..9 "3""#]],
+        );
+    }
+
+    #[test]
+    fn parse_left_associativity() {
+        check_expr(
+            "a - b - c",
+            expect![[r#"
+                BinaryExpr@0..9
+                  BinaryExpr@0..6
+                    NameRef@0..2
+                      Ident@0..1 "a"
+                      Whitespace@1..2 " "
+                    Minus@2..3 "-"
+                    Whitespace@3..4 " "
+                    NameRef@4..6
+                      Ident@4..5 "b"
+                      Whitespace@5..6 " "
+                  Minus@6..7 "-"
+                  Whitespace@7..8 " "
+                  NameRef@8..9
+                    Ident@8..9 "c""#]],
+        );
+    }
+
+    #[test]
+    fn parse_prefix_and_parens() {
+        check_expr(
+            "-(a || !b) == c",
+            expect![[r#"
+                BinaryExpr@0..15
+                  PrefixExpr@0..11
+                    Minus@0..1 "-"
+                    ParenExpr@1..11
+                      OpenParen@1..2 "("
+                      BinaryExpr@2..9
+                        NameRef@2..4
+                          Ident@2..3 "a"
+                          Whitespace@3..4 " "
+                        OrOr@4..6 "||"
+                        Whitespace@6..7 " "
+                        PrefixExpr@7..9
+                          Not@7..8 "!"
+                          NameRef@8..9
+                            Ident@8..9 "b"
+                      CloseParen@9..10 ")"
+                      Whitespace@10..11 " "
+                  EqualEquals@11..13 "=="
+                  Whitespace@13..14 " "
+                  NameRef@14..15
+                    Ident@14..15 "c""#]],
+        );
+    }
+
+    #[test]
+    fn parse_call() {
+        check_expr(
+            "add(1, 2)",
+            expect![[r#"
+                CallExpr@0..9
+                  NameRef@0..3
+                    Ident@0..3 "add"
+                  ArgList@3..9
+                    OpenParen@3..4 "("
+                    Literal@4..5
+                      Int@4..5 "1"
+                    Comma@5..6 ","
+                    Whitespace@6..7 " "
+                    Literal@7..8
+                      Int@7..8 "2"
+                    CloseParen@8..9 ")""#]],
+        );
+    }
+
+    #[test]
+    fn parse_empty_call() {
+        check_expr(
+            "f()",
+            expect![[r#"
+                CallExpr@0..3
+                  NameRef@0..1
+                    Ident@0..1 "f"
+                  ArgList@1..3
+                    OpenParen@1..2 "("
+                    CloseParen@2..3 ")""#]],
+        );
+    }
+
+    #[test]
+    fn parse_chained_call() {
+        check_expr(
+            "f(a)(b)",
+            expect![[r#"
+                CallExpr@0..7
+                  CallExpr@0..4
+                    NameRef@0..1
+                      Ident@0..1 "f"
+                    ArgList@1..4
+                      OpenParen@1..2 "("
+                      NameRef@2..3
+                        Ident@2..3 "a"
+                      CloseParen@3..4 ")"
+                  ArgList@4..7
+                    OpenParen@4..5 "("
+                    NameRef@5..6
+                      Ident@5..6 "b"
+                    CloseParen@6..7 ")""#]],
+        );
+    }
+
+    #[test]
+    fn parse_call_trailing_comma() {
+        check_expr(
+            "f(1,)",
+            expect![[r#"
+                CallExpr@0..5
+                  NameRef@0..1
+                    Ident@0..1 "f"
+                  ArgList@1..5
+                    OpenParen@1..2 "("
+                    Literal@2..3
+                      Int@2..3 "1"
+                    Comma@3..4 ","
+                    CloseParen@4..5 ")""#]],
+        );
+    }
+
+    #[test]
+    fn parse_unclosed_call() {
+        check_expr(
+            "f(1, }",
+            expect![[r#"
+                CallExpr@0..5
+                  NameRef@0..1
+                    Ident@0..1 "f"
+                  ArgList@1..5
+                    OpenParen@1..2 "("
+                    Literal@2..3
+                      Int@2..3 "1"
+                    Comma@3..4 ","
+                    Whitespace@4..5 " ""#]],
+        );
+    }
+
+    #[test]
+    fn parse_field() {
+        check_expr(
+            "a.b",
+            expect![[r#"
+                FieldExpr@0..3
+                  NameRef@0..1
+                    Ident@0..1 "a"
+                  Dot@1..2 "."
+                  Ident@2..3 "b""#]],
+        );
+    }
+
+    #[test]
+    fn parse_nested_field() {
+        check_expr(
+            "a.b.c",
+            expect![[r#"
+                FieldExpr@0..5
+                  FieldExpr@0..3
+                    NameRef@0..1
+                      Ident@0..1 "a"
+                    Dot@1..2 "."
+                    Ident@2..3 "b"
+                  Dot@3..4 "."
+                  Ident@4..5 "c""#]],
+        );
+    }
+
+    #[test]
+    fn parse_method_call() {
+        check_expr(
+            "a.b(c).d",
+            expect![[r#"
+                FieldExpr@0..8
+                  MethodCallExpr@0..6
+                    NameRef@0..1
+                      Ident@0..1 "a"
+                    Dot@1..2 "."
+                    Ident@2..3 "b"
+                    ArgList@3..6
+                      OpenParen@3..4 "("
+                      NameRef@4..5
+                        Ident@4..5 "c"
+                      CloseParen@5..6 ")"
+                  Dot@6..7 "."
+                  Ident@7..8 "d""#]],
+        );
+    }
+
+    #[test]
+    fn parse_nested_tuple_index() {
+        check_expr(
+            "a.0.1.b",
+            expect![[r#"
+                FieldExpr@0..7
+                  FieldExpr@0..5
+                    FieldExpr@0..3
+                      NameRef@0..1
+                        Ident@0..1 "a"
+                      Dot@1..2 "."
+                      Int@2..3 "0"
+                    Dot@3..4 "."
+                    Int@4..5 "1"
+                  Dot@5..6 "."
+                  Ident@6..7 "b""#]],
+        );
+    }
+
+    #[test]
+    fn parse_tuple_index() {
+        check_expr(
+            "-a.0 + 1",
+            expect![[r#"
+                BinaryExpr@0..8
+                  PrefixExpr@0..5
+                    Minus@0..1 "-"
+                    FieldExpr@1..5
+                      NameRef@1..2
+                        Ident@1..2 "a"
+                      Dot@2..3 "."
+                      Int@3..4 "0"
+                      Whitespace@4..5 " "
+                  Plus@5..6 "+"
+                  Whitespace@6..7 " "
+                  Literal@7..8
+                    Int@7..8 "1""#]],
+        );
+    }
+
+    #[test]
+    fn parse_missing_field() {
+        check_expr(
+            "a.;",
+            expect![[r#"
+                FieldExpr@0..2
+                  NameRef@0..1
+                    Ident@0..1 "a"
+                  Dot@1..2 ".""#]],
+        );
+    }
+
+    #[test]
+    fn parse_missing_operand() {
+        check_expr(
+            "1 +",
+            expect![[r#"
+                BinaryExpr@0..3
+                  Literal@0..2
+                    Int@0..1 "1"
+                    Whitespace@1..2 " "
+                  Plus@2..3 "+"
+                  ErrorNode@3..3"#]],
+        );
+    }
+
+    #[test]
+    fn parse_if() {
+        check_expr(
+            "if a {}",
+            expect![[r#"
+                IfExpr@0..7
+                  If@0..2 "if"
+                  Whitespace@2..3 " "
+                  NameRef@3..5
+                    Ident@3..4 "a"
+                    Whitespace@4..5 " "
+                  Block@5..7
+                    OpenBrace@5..6 "{"
+                    CloseBrace@6..7 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_if_else() {
+        check_expr(
+            "if a {} else {}",
+            expect![[r#"
+                IfExpr@0..15
+                  If@0..2 "if"
+                  Whitespace@2..3 " "
+                  NameRef@3..5
+                    Ident@3..4 "a"
+                    Whitespace@4..5 " "
+                  Block@5..8
+                    OpenBrace@5..6 "{"
+                    CloseBrace@6..7 "}"
+                    Whitespace@7..8 " "
+                  Else@8..12 "else"
+                  Whitespace@12..13 " "
+                  Block@13..15
+                    OpenBrace@13..14 "{"
+                    CloseBrace@14..15 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_else_if() {
+        check_expr(
+            "if a {} else if b {}",
+            expect![[r#"
+                IfExpr@0..20
+                  If@0..2 "if"
+                  Whitespace@2..3 " "
+                  NameRef@3..5
+                    Ident@3..4 "a"
+                    Whitespace@4..5 " "
+                  Block@5..8
+                    OpenBrace@5..6 "{"
+                    CloseBrace@6..7 "}"
+                    Whitespace@7..8 " "
+                  Else@8..12 "else"
+                  Whitespace@12..13 " "
+                  IfExpr@13..20
+                    If@13..15 "if"
+                    Whitespace@15..16 " "
+                    NameRef@16..18
+                      Ident@16..17 "b"
+                      Whitespace@17..18 " "
+                    Block@18..20
+                      OpenBrace@18..19 "{"
+                      CloseBrace@19..20 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_else_expr() {
+        check_expr(
+            "if a {} else 5 + 1",
+            expect![[r#"
+                IfExpr@0..18
+                  If@0..2 "if"
+                  Whitespace@2..3 " "
+                  NameRef@3..5
+                    Ident@3..4 "a"
+                    Whitespace@4..5 " "
+                  Block@5..8
+                    OpenBrace@5..6 "{"
+                    CloseBrace@6..7 "}"
+                    Whitespace@7..8 " "
+                  Else@8..12 "else"
+                  Whitespace@12..13 " "
+                  ErrorNode@13..18
+                    BinaryExpr@13..18
+                      Literal@13..15
+                        Int@13..14 "5"
+                        Whitespace@14..15 " "
+                      Plus@15..16 "+"
+                      Whitespace@16..17 " "
+                      Literal@17..18
+                        Int@17..18 "1""#]],
+        );
+
+        let tokens: Vec<Token> = Lexer::new("if a {} else 5 + 1").collect();
+        let mut parser = Parser::new(&tokens);
+        parse_expr(&mut parser);
+        assert_eq!(
+            parser.build().errors,
+            [ParseError::new(
+                TextRange::new(13.into(), 14.into()),
+                "expected block or `if` after `else`, wrap the expression in braces"
+            )]
+        );
+    }
+
+    #[test]
+    fn parse_if_missing_condition_and_block() {
+        check_expr(
+            "if {}",
+            expect![[r#"
+                IfExpr@0..5
+                  If@0..2 "if"
+                  Whitespace@2..3 " "
+                  ErrorNode@3..3
+                  Block@3..5
+                    OpenBrace@3..4 "{"
+                    CloseBrace@4..5 "}""#]],
+        );
+        check_expr(
+            "if a",
+            expect![[r#"
+                IfExpr@0..4
+                  If@0..2 "if"
+                  Whitespace@2..3 " "
+                  NameRef@3..4
+                    Ident@3..4 "a"
+                  ErrorNode@4..4"#]],
+        );
+    }
+
+    #[test]
+    fn parse_while() {
+        check_expr(
+            "while x {}",
+            expect![[r#"
+                WhileExpr@0..10
+                  While@0..5 "while"
+                  Whitespace@5..6 " "
+                  NameRef@6..8
+                    Ident@6..7 "x"
+                    Whitespace@7..8 " "
+                  Block@8..10
+                    OpenBrace@8..9 "{"
+                    CloseBrace@9..10 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_while_missing_condition() {
+        check_expr(
+            "while {}",
+            expect![[r#"
+                WhileExpr@0..8
+                  While@0..5 "while"
+                  Whitespace@5..6 " "
+                  ErrorNode@6..6
+                  Block@6..8
+                    OpenBrace@6..7 "{"
+                    CloseBrace@7..8 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_interp_string() {
+        check_expr(
+            r#""a ${b + 1} c""#,
+            expect![[r#"
+                InterpString@0..14
+                  StringFragment@0..3 "\"a "
+                  InterpStart@3..5 "${"
+                  BinaryExpr@5..10
+                    NameRef@5..7
+                      Ident@5..6 "b"
+                      Whitespace@6..7 " "
+                    Plus@7..8 "+"
+                    Whitespace@8..9 " "
+                    Literal@9..10
+                      Int@9..10 "1"
+                  InterpEnd@10..11 "}"
+                  StringFragment@11..14 " c\"""#]],
+        );
+    }
+
+    #[test]
+    fn parse_unclosed_interp_string() {
+        check_expr(
+            r#""a ${b"#,
+            expect![[r#"
+                InterpString@0..6
+                  StringFragment@0..3 "\"a "
+                  InterpStart@3..5 "${"
+                  NameRef@5..6
+                    Ident@5..6 "b""#]],
+        );
+    }
+
+    #[test]
+    fn parse_return_binary() {
+        check_expr(
+            "return a + 1",
+            expect![[r#"
+                ReturnExpr@0..12
+                  Return@0..6 "return"
+                  Whitespace@6..7 " "
+                  BinaryExpr@7..12
+                    NameRef@7..9
+                      Ident@7..8 "a"
+                      Whitespace@8..9 " "
+                    Plus@9..10 "+"
+                    Whitespace@10..11 " "
+                    Literal@11..12
+                      Int@11..12 "1""#]],
+        );
+    }
+
+    #[test]
+    fn typed_binary_expr() {
+        let tokens: Vec<Token> = Lexer::new("1 + 2").collect();
+        let mut parser = Parser::new(&tokens);
+        parse_expr(&mut parser);
+
+        let expr = BinaryExpr::cast(parser.build().node).unwrap();
+        assert_eq!(expr.op().unwrap().kind(), SyntaxKind::Plus);
+
+        let Some(Expr::Literal(lhs)) = expr.lhs() else {
+            panic!("expected a literal");
+        };
+        assert_eq!(lhs.kind(), Some(LiteralKind::Int));
+        assert_eq!(lhs.token().unwrap().text(), "1");
+
+        let Some(Expr::Literal(rhs)) = expr.rhs() else {
+            panic!("expected a literal");
+        };
+        assert_eq!(rhs.token().unwrap().text(), "2");
+    }
+}
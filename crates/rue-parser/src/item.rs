@@ -0,0 +1,1073 @@
+use rue_syntax::SyntaxKind;
+
+use crate::{
+    block::parse_block,
+    expr::parse_args,
+    parser::Parser,
+    ty::{at_type, parse_type},
+};
+
+/// Parses an attribute such as `#[test]` or `#[doc(1)]`, or an inner attribute such
+/// as `#![name]` which applies to the whole program.
+pub fn parse_attr(p: &mut Parser) {
+    p.start(SyntaxKind::Attr);
+    p.expect(SyntaxKind::Hash);
+    if p.at(SyntaxKind::Not) {
+        p.expect(SyntaxKind::Not);
+    }
+    p.expect_recover(
+        SyntaxKind::OpenBracket,
+        &[
+            &[SyntaxKind::Ident, SyntaxKind::CloseBracket],
+            ITEM_RECOVERY,
+        ]
+        .concat(),
+    );
+    p.expect_recover(
+        SyntaxKind::Ident,
+        &[
+            &[SyntaxKind::OpenParen, SyntaxKind::CloseBracket],
+            ITEM_RECOVERY,
+        ]
+        .concat(),
+    );
+    if p.at(SyntaxKind::OpenParen) {
+        parse_args(p);
+    }
+    p.expect_recover(SyntaxKind::CloseBracket, ITEM_RECOVERY);
+    p.finish();
+}
+
+pub fn at_inner_attr(p: &mut Parser) -> bool {
+    p.at(SyntaxKind::Hash) && p.nth(1) == SyntaxKind::Not
+}
+
+/// Attributes before an item are part of its node.
+fn parse_outer_attrs(p: &mut Parser) {
+    while p.at(SyntaxKind::Hash) && !at_inner_attr(p) {
+        parse_attr(p);
+    }
+}
+
+/// Parses an optional visibility such as `pub`, `pub(crate)` or `pub(super)`.
+/// Items without one are private, and get no node.
+pub fn parse_visibility(p: &mut Parser) {
+    if !p.at(SyntaxKind::Pub) {
+        return;
+    }
+
+    p.start(SyntaxKind::Visibility);
+    p.expect(SyntaxKind::Pub);
+    if p.eat(SyntaxKind::OpenParen) {
+        if !p.eat(SyntaxKind::Crate) && !p.eat(SyntaxKind::Super) {
+            let message = "expected `crate` or `super`".to_string();
+            if p.at(SyntaxKind::CloseParen) {
+                p.missing(message);
+            } else {
+                p.error(message);
+            }
+        }
+        p.expect_recover(SyntaxKind::CloseParen, ITEM_RECOVERY);
+    }
+    p.finish();
+}
+
+/// The kind of the first token of an item, looking past any attributes and
+/// visibility before it.
+pub fn item_start(p: &mut Parser) -> SyntaxKind {
+    let mut n = 0;
+
+    while p.nth(n) == SyntaxKind::Hash && p.nth(n + 1) == SyntaxKind::OpenBracket {
+        let mut depth = 0;
+        n += 1;
+
+        loop {
+            match p.nth(n) {
+                SyntaxKind::OpenBracket => depth += 1,
+                SyntaxKind::CloseBracket => depth -= 1,
+                SyntaxKind::Eof => return SyntaxKind::Eof,
+                _ => {}
+            }
+            n += 1;
+
+            if depth == 0 {
+                break;
+            }
+        }
+    }
+
+    if p.nth(n) == SyntaxKind::Pub {
+        n += 1;
+
+        if p.nth(n) == SyntaxKind::OpenParen {
+            while !matches!(p.nth(n), SyntaxKind::CloseParen | SyntaxKind::Eof) {
+                n += 1;
+            }
+            n += 1;
+        }
+    }
+
+    p.nth(n)
+}
+
+pub fn parse_fn(p: &mut Parser) {
+    p.start(SyntaxKind::Function);
+    parse_outer_attrs(p);
+    parse_visibility(p);
+    p.expect(SyntaxKind::Fn);
+    p.expect_recover(
+        SyntaxKind::Ident,
+        &[
+            SyntaxKind::LessThan,
+            SyntaxKind::OpenParen,
+            SyntaxKind::OpenBrace,
+        ],
+    );
+
+    if p.at(SyntaxKind::LessThan) {
+        parse_generic_params(p);
+    }
+
+    parse_params(p);
+
+    if p.at(SyntaxKind::Arrow) {
+        parse_ret_type(p);
+    }
+
+    parse_block(p);
+    p.finish();
+}
+
+/// Parses the type parameters of an item, such as `<T, U>`.
+fn parse_generic_params(p: &mut Parser) {
+    p.start(SyntaxKind::GenericParamList);
+    p.expect(SyntaxKind::LessThan);
+
+    let recovery = [
+        &[
+            SyntaxKind::GreaterThan,
+            SyntaxKind::OpenParen,
+            SyntaxKind::OpenBrace,
+            SyntaxKind::Semicolon,
+        ],
+        ITEM_RECOVERY,
+    ]
+    .concat();
+
+    while !p.at_eof() && !p.at_any(&recovery) {
+        p.start(SyntaxKind::TypeParam);
+        p.expect_recover(SyntaxKind::Ident, &recovery);
+        p.finish();
+        if !p.at(SyntaxKind::GreaterThan) {
+            p.expect_recover(SyntaxKind::Comma, &recovery);
+        }
+    }
+
+    p.expect_recover(SyntaxKind::GreaterThan, &recovery);
+    p.finish();
+}
+
+pub fn parse_params(p: &mut Parser) {
+    p.start(SyntaxKind::ParamList);
+    p.expect_recover(SyntaxKind::OpenParen, &[SyntaxKind::OpenBrace]);
+
+    while !p.at_any(&[
+        SyntaxKind::CloseParen,
+        SyntaxKind::OpenBrace,
+        SyntaxKind::Eof,
+    ]) {
+        parse_param(p);
+        if !p.at(SyntaxKind::CloseParen) {
+            p.expect_recover(
+                SyntaxKind::Comma,
+                &[SyntaxKind::CloseParen, SyntaxKind::OpenBrace],
+            );
+        }
+    }
+
+    p.expect_recover(
+        SyntaxKind::CloseParen,
+        &[SyntaxKind::Arrow, SyntaxKind::OpenBrace],
+    );
+    p.finish();
+}
+
+pub fn parse_ret_type(p: &mut Parser) {
+    p.start(SyntaxKind::RetType);
+    p.expect(SyntaxKind::Arrow);
+    if at_type(p) {
+        parse_type(p);
+    } else {
+        p.missing("expected type".to_string());
+    }
+    p.finish();
+}
+
+fn parse_param(p: &mut Parser) {
+    parse_field(
+        p,
+        SyntaxKind::Param,
+        &[
+            SyntaxKind::Comma,
+            SyntaxKind::CloseParen,
+            SyntaxKind::OpenBrace,
+        ],
+    );
+}
+
+/// The tokens which can start a top-level item, used to stop parsing a broken item
+/// before it swallows the next one.
+const ITEM_RECOVERY: &[SyntaxKind] = &[SyntaxKind::Fn, SyntaxKind::Struct, SyntaxKind::Enum];
+
+pub fn parse_struct(p: &mut Parser) {
+    p.start(SyntaxKind::StructItem);
+    parse_outer_attrs(p);
+    parse_visibility(p);
+    p.expect(SyntaxKind::Struct);
+    p.expect_recover(
+        SyntaxKind::Ident,
+        &[
+            &[
+                SyntaxKind::LessThan,
+                SyntaxKind::OpenBrace,
+                SyntaxKind::Semicolon,
+            ],
+            ITEM_RECOVERY,
+        ]
+        .concat(),
+    );
+
+    if p.at(SyntaxKind::LessThan) {
+        parse_generic_params(p);
+    }
+
+    if !p.eat(SyntaxKind::Semicolon) {
+        parse_fields(p);
+    }
+
+    p.finish();
+}
+
+fn parse_fields(p: &mut Parser) {
+    p.start(SyntaxKind::FieldList);
+    p.expect_open(SyntaxKind::OpenBrace, ITEM_RECOVERY);
+
+    let recovery = [&[SyntaxKind::CloseBrace], ITEM_RECOVERY].concat();
+
+    while !p.at_eof() && !p.at_any(&recovery) {
+        parse_field(
+            p,
+            SyntaxKind::Field,
+            &[&[SyntaxKind::Comma], recovery.as_slice()].concat(),
+        );
+        if !p.at(SyntaxKind::CloseBrace) {
+            p.expect_recover(SyntaxKind::Comma, &recovery);
+        }
+    }
+
+    p.expect_close(SyntaxKind::CloseBrace, ITEM_RECOVERY);
+    p.finish();
+}
+
+pub fn parse_enum(p: &mut Parser) {
+    p.start(SyntaxKind::EnumItem);
+    parse_outer_attrs(p);
+    parse_visibility(p);
+    p.expect(SyntaxKind::Enum);
+    p.expect_recover(
+        SyntaxKind::Ident,
+        &[&[SyntaxKind::OpenBrace], ITEM_RECOVERY].concat(),
+    );
+    parse_variants(p);
+    p.finish();
+}
+
+fn parse_variants(p: &mut Parser) {
+    let checkpoint = p.checkpoint();
+    p.expect_open(SyntaxKind::OpenBrace, ITEM_RECOVERY);
+
+    let recovery = [&[SyntaxKind::CloseBrace], ITEM_RECOVERY].concat();
+
+    while !p.at_eof() && !p.at_any(&recovery) {
+        parse_variant(p, &recovery);
+        if !p.at(SyntaxKind::CloseBrace) {
+            p.expect_recover(SyntaxKind::Comma, &recovery);
+        }
+    }
+
+    p.expect_close(SyntaxKind::CloseBrace, ITEM_RECOVERY);
+    p.start_at(checkpoint, SyntaxKind::VariantList);
+    p.finish();
+}
+
+fn parse_variant(p: &mut Parser, recovery: &[SyntaxKind]) {
+    p.start(SyntaxKind::Variant);
+    p.expect_recover(
+        SyntaxKind::Ident,
+        &[&[SyntaxKind::OpenParen, SyntaxKind::Comma], recovery].concat(),
+    );
+
+    if p.at(SyntaxKind::OpenParen) {
+        parse_variant_types(p, recovery);
+    }
+
+    p.finish();
+}
+
+/// Parses the types carried by a variant, as in `(Int, Bool)`.
+fn parse_variant_types(p: &mut Parser, recovery: &[SyntaxKind]) {
+    p.start(SyntaxKind::TypeList);
+    p.expect(SyntaxKind::OpenParen);
+
+    while !p.at(SyntaxKind::CloseParen) && !p.at_eof() && !p.at_any(recovery) {
+        parse_type(p);
+        if !p.at(SyntaxKind::CloseParen) {
+            p.expect_recover(
+                SyntaxKind::Comma,
+                &[&[SyntaxKind::CloseParen], recovery].concat(),
+            );
+        }
+    }
+
+    p.expect_recover(
+        SyntaxKind::CloseParen,
+        &[&[SyntaxKind::Comma], recovery].concat(),
+    );
+    p.finish();
+}
+
+/// Parses a name and type pair, as in `name: Type`. This is shared by parameters and
+/// struct fields, which only differ in where they end.
+fn parse_field(p: &mut Parser, kind: SyntaxKind, recovery: &[SyntaxKind]) {
+    p.start(kind);
+    p.expect_recover(
+        SyntaxKind::Ident,
+        &[&[SyntaxKind::Colon], recovery].concat(),
+    );
+    p.expect_recover(SyntaxKind::Colon, recovery);
+    parse_type(p);
+    p.finish();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use expect_test::{expect, Expect};
+    use rue_lexer::{Lexer, Token};
+
+    use crate::program::parse_program;
+
+    crate::check!(check_program, parse_program);
+    crate::check!(check_fn, parse_fn);
+    crate::check!(check_params, parse_params);
+    crate::check!(check_struct, parse_struct);
+    crate::check!(check_enum, parse_enum);
+    crate::check!(check_attr, parse_attr);
+
+    #[test]
+    fn parse_empty_fn() {
+        check_fn(
+            "fn main() {}",
+            expect![[r#"
+                Function@0..12
+                  Fn@0..2 "fn"
+                  Whitespace@2..3 " "
+                  Ident@3..7 "main"
+                  ParamList@7..10
+                    OpenParen@7..8 "("
+                    CloseParen@8..9 ")"
+                    Whitespace@9..10 " "
+                  Block@10..12
+                    OpenBrace@10..11 "{"
+                    CloseBrace@11..12 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_generic_fn() {
+        check_fn(
+            "fn f<T>() {}",
+            expect![[r#"
+                Function@0..12
+                  Fn@0..2 "fn"
+                  Whitespace@2..3 " "
+                  Ident@3..4 "f"
+                  GenericParamList@4..7
+                    LessThan@4..5 "<"
+                    TypeParam@5..6
+                      Ident@5..6 "T"
+                    GreaterThan@6..7 ">"
+                  ParamList@7..10
+                    OpenParen@7..8 "("
+                    CloseParen@8..9 ")"
+                    Whitespace@9..10 " "
+                  Block@10..12
+                    OpenBrace@10..11 "{"
+                    CloseBrace@11..12 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_fn_return_type() {
+        check_fn(
+            "fn main() -> Int {}",
+            expect![[r#"
+                Function@0..19
+                  Fn@0..2 "fn"
+                  Whitespace@2..3 " "
+                  Ident@3..7 "main"
+                  ParamList@7..10
+                    OpenParen@7..8 "("
+                    CloseParen@8..9 ")"
+                    Whitespace@9..10 " "
+                  RetType@10..17
+                    Arrow@10..12 "->"
+                    Whitespace@12..13 " "
+                    PathType@13..17
+                      Ident@13..16 "Int"
+                      Whitespace@16..17 " "
+                  Block@17..19
+                    OpenBrace@17..18 "{"
+                    CloseBrace@18..19 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_malformed_fn_header() {
+        check_fn(
+            "fn (x {}",
+            expect![[r#"
+                Function@0..8
+                  Fn@0..2 "fn"
+                  Whitespace@2..3 " "
+                  ParamList@3..6
+                    OpenParen@3..4 "("
+                    Param@4..6
+                      Ident@4..5 "x"
+                      Whitespace@5..6 " "
+                  Block@6..8
+                    OpenBrace@6..7 "{"
+                    CloseBrace@7..8 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_empty_params() {
+        check_params(
+            "()",
+            expect![[r#"
+                ParamList@0..2
+                  OpenParen@0..1 "("
+                  CloseParen@1..2 ")""#]],
+        );
+    }
+
+    #[test]
+    fn parse_single_param() {
+        check_params(
+            "(a: Int)",
+            expect![[r#"
+                ParamList@0..8
+                  OpenParen@0..1 "("
+                  Param@1..7
+                    Ident@1..2 "a"
+                    Colon@2..3 ":"
+                    Whitespace@3..4 " "
+                    PathType@4..7
+                      Ident@4..7 "Int"
+                  CloseParen@7..8 ")""#]],
+        );
+    }
+
+    #[test]
+    fn parse_trailing_comma_params() {
+        check_params(
+            "(a: Int, b: Int,)",
+            expect![[r#"
+                ParamList@0..17
+                  OpenParen@0..1 "("
+                  Param@1..7
+                    Ident@1..2 "a"
+                    Colon@2..3 ":"
+                    Whitespace@3..4 " "
+                    PathType@4..7
+                      Ident@4..7 "Int"
+                  Comma@7..8 ","
+                  Whitespace@8..9 " "
+                  Param@9..15
+                    Ident@9..10 "b"
+                    Colon@10..11 ":"
+                    Whitespace@11..12 " "
+                    PathType@12..15
+                      Ident@12..15 "Int"
+                  Comma@15..16 ","
+                  CloseParen@16..17 ")""#]],
+        );
+    }
+
+    #[test]
+    fn parse_missing_param_type() {
+        check_params(
+            "(a: )",
+            expect![[r#"
+                ParamList@0..5
+                  OpenParen@0..1 "("
+                  Param@1..4
+                    Ident@1..2 "a"
+                    Colon@2..3 ":"
+                    Whitespace@3..4 " "
+                  CloseParen@4..5 ")""#]],
+        );
+    }
+
+    #[test]
+    fn parse_unclosed_params() {
+        check_fn(
+            "fn f(a: Int {}",
+            expect![[r#"
+                Function@0..14
+                  Fn@0..2 "fn"
+                  Whitespace@2..3 " "
+                  Ident@3..4 "f"
+                  ParamList@4..12
+                    OpenParen@4..5 "("
+                    Param@5..12
+                      Ident@5..6 "a"
+                      Colon@6..7 ":"
+                      Whitespace@7..8 " "
+                      PathType@8..12
+                        Ident@8..11 "Int"
+                        Whitespace@11..12 " "
+                  Block@12..14
+                    OpenBrace@12..13 "{"
+                    CloseBrace@13..14 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_ret_type() {
+        check_fn(
+            "fn f() -> Int {}",
+            expect![[r#"
+                Function@0..16
+                  Fn@0..2 "fn"
+                  Whitespace@2..3 " "
+                  Ident@3..4 "f"
+                  ParamList@4..7
+                    OpenParen@4..5 "("
+                    CloseParen@5..6 ")"
+                    Whitespace@6..7 " "
+                  RetType@7..14
+                    Arrow@7..9 "->"
+                    Whitespace@9..10 " "
+                    PathType@10..14
+                      Ident@10..13 "Int"
+                      Whitespace@13..14 " "
+                  Block@14..16
+                    OpenBrace@14..15 "{"
+                    CloseBrace@15..16 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_missing_ret_type() {
+        check_fn(
+            "fn f() -> {}",
+            expect![[r#"
+                Function@0..12
+                  Fn@0..2 "fn"
+                  Whitespace@2..3 " "
+                  Ident@3..4 "f"
+                  ParamList@4..7
+                    OpenParen@4..5 "("
+                    CloseParen@5..6 ")"
+                    Whitespace@6..7 " "
+                  RetType@7..10
+                    Arrow@7..9 "->"
+                    Whitespace@9..10 " "
+                    ErrorNode@10..10
+                  Block@10..12
+                    OpenBrace@10..11 "{"
+                    CloseBrace@11..12 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_struct_fields() {
+        check_struct(
+            "struct Point { x: Int, y: Int }",
+            expect![[r#"
+                StructItem@0..31
+                  Struct@0..6 "struct"
+                  Whitespace@6..7 " "
+                  Ident@7..12 "Point"
+                  Whitespace@12..13 " "
+                  FieldList@13..31
+                    OpenBrace@13..14 "{"
+                    Whitespace@14..15 " "
+                    Field@15..21
+                      Ident@15..16 "x"
+                      Colon@16..17 ":"
+                      Whitespace@17..18 " "
+                      PathType@18..21
+                        Ident@18..21 "Int"
+                    Comma@21..22 ","
+                    Whitespace@22..23 " "
+                    Field@23..30
+                      Ident@23..24 "y"
+                      Colon@24..25 ":"
+                      Whitespace@25..26 " "
+                      PathType@26..30
+                        Ident@26..29 "Int"
+                        Whitespace@29..30 " "
+                    CloseBrace@30..31 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_empty_struct() {
+        check_struct(
+            "struct Empty {}",
+            expect![[r#"
+                StructItem@0..15
+                  Struct@0..6 "struct"
+                  Whitespace@6..7 " "
+                  Ident@7..12 "Empty"
+                  Whitespace@12..13 " "
+                  FieldList@13..15
+                    OpenBrace@13..14 "{"
+                    CloseBrace@14..15 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_generic_struct() {
+        check_struct(
+            "struct S<T, U> {}",
+            expect![[r#"
+                StructItem@0..17
+                  Struct@0..6 "struct"
+                  Whitespace@6..7 " "
+                  Ident@7..8 "S"
+                  GenericParamList@8..15
+                    LessThan@8..9 "<"
+                    TypeParam@9..10
+                      Ident@9..10 "T"
+                    Comma@10..11 ","
+                    Whitespace@11..12 " "
+                    TypeParam@12..13
+                      Ident@12..13 "U"
+                    GreaterThan@13..14 ">"
+                    Whitespace@14..15 " "
+                  FieldList@15..17
+                    OpenBrace@15..16 "{"
+                    CloseBrace@16..17 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_unit_struct() {
+        check_struct(
+            "struct Unit;",
+            expect![[r#"
+                StructItem@0..12
+                  Struct@0..6 "struct"
+                  Whitespace@6..7 " "
+                  Ident@7..11 "Unit"
+                  Semicolon@11..12 ";""#]],
+        );
+    }
+
+    #[test]
+    fn parse_malformed_field() {
+        check_struct(
+            "struct Point { x Int, : Int, y: }",
+            expect![[r#"
+                StructItem@0..33
+                  Struct@0..6 "struct"
+                  Whitespace@6..7 " "
+                  Ident@7..12 "Point"
+                  Whitespace@12..13 " "
+                  FieldList@13..33
+                    OpenBrace@13..14 "{"
+                    Whitespace@14..15 " "
+                    Field@15..20
+                      Ident@15..16 "x"
+                      Whitespace@16..17 " "
+                      ErrorNode@17..20
+                        Ident@17..20 "Int"
+                    Comma@20..21 ","
+                    Whitespace@21..22 " "
+                    Field@22..27
+                      Colon@22..23 ":"
+                      Whitespace@23..24 " "
+                      PathType@24..27
+                        Ident@24..27 "Int"
+                    Comma@27..28 ","
+                    Whitespace@28..29 " "
+                    Field@29..32
+                      Ident@29..30 "y"
+                      Colon@30..31 ":"
+                      Whitespace@31..32 " "
+                    CloseBrace@32..33 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_struct_missing_brace() {
+        check_program(
+            "struct Point { x: Int\nfn main() {}",
+            expect![[r#"
+                Program@0..34
+                  StructItem@0..22
+                    Struct@0..6 "struct"
+                    Whitespace@6..7 " "
+                    Ident@7..12 "Point"
+                    Whitespace@12..13 " "
+                    FieldList@13..22
+                      OpenBrace@13..14 "{"
+                      Whitespace@14..15 " "
+                      Field@15..22
+                        Ident@15..16 "x"
+                        Colon@16..17 ":"
+                        Whitespace@17..18 " "
+                        PathType@18..22
+                          Ident@18..21 "Int"
+                          Whitespace@21..22 "\n"
+                  Function@22..34
+                    Fn@22..24 "fn"
+                    Whitespace@24..25 " "
+                    Ident@25..29 "main"
+                    ParamList@29..32
+                      OpenParen@29..30 "("
+                      CloseParen@30..31 ")"
+                      Whitespace@31..32 " "
+                    Block@32..34
+                      OpenBrace@32..33 "{"
+                      CloseBrace@33..34 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_c_like_enum() {
+        check_enum(
+            "enum Color { Red, Green, Blue }",
+            expect![[r#"
+                EnumItem@0..31
+                  Enum@0..4 "enum"
+                  Whitespace@4..5 " "
+                  Ident@5..10 "Color"
+                  Whitespace@10..11 " "
+                  VariantList@11..31
+                    OpenBrace@11..12 "{"
+                    Whitespace@12..13 " "
+                    Variant@13..16
+                      Ident@13..16 "Red"
+                    Comma@16..17 ","
+                    Whitespace@17..18 " "
+                    Variant@18..23
+                      Ident@18..23 "Green"
+                    Comma@23..24 ","
+                    Whitespace@24..25 " "
+                    Variant@25..30
+                      Ident@25..29 "Blue"
+                      Whitespace@29..30 " "
+                    CloseBrace@30..31 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_enum_payload() {
+        check_enum(
+            "enum Shape { Circle(Float), Rect(Float, Float) }",
+            expect![[r#"
+                EnumItem@0..48
+                  Enum@0..4 "enum"
+                  Whitespace@4..5 " "
+                  Ident@5..10 "Shape"
+                  Whitespace@10..11 " "
+                  VariantList@11..48
+                    OpenBrace@11..12 "{"
+                    Whitespace@12..13 " "
+                    Variant@13..26
+                      Ident@13..19 "Circle"
+                      TypeList@19..26
+                        OpenParen@19..20 "("
+                        PathType@20..25
+                          Ident@20..25 "Float"
+                        CloseParen@25..26 ")"
+                    Comma@26..27 ","
+                    Whitespace@27..28 " "
+                    Variant@28..47
+                      Ident@28..32 "Rect"
+                      TypeList@32..47
+                        OpenParen@32..33 "("
+                        PathType@33..38
+                          Ident@33..38 "Float"
+                        Comma@38..39 ","
+                        Whitespace@39..40 " "
+                        PathType@40..45
+                          Ident@40..45 "Float"
+                        CloseParen@45..46 ")"
+                        Whitespace@46..47 " "
+                    CloseBrace@47..48 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_enum_trailing_comma() {
+        check_enum(
+            "enum Option { None, Some(Int), }",
+            expect![[r#"
+                EnumItem@0..32
+                  Enum@0..4 "enum"
+                  Whitespace@4..5 " "
+                  Ident@5..11 "Option"
+                  Whitespace@11..12 " "
+                  VariantList@12..32
+                    OpenBrace@12..13 "{"
+                    Whitespace@13..14 " "
+                    Variant@14..18
+                      Ident@14..18 "None"
+                    Comma@18..19 ","
+                    Whitespace@19..20 " "
+                    Variant@20..29
+                      Ident@20..24 "Some"
+                      TypeList@24..29
+                        OpenParen@24..25 "("
+                        PathType@25..28
+                          Ident@25..28 "Int"
+                        CloseParen@28..29 ")"
+                    Comma@29..30 ","
+                    Whitespace@30..31 " "
+                    CloseBrace@31..32 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_enum_missing_brace() {
+        check_program(
+            "enum Color { Red\nfn main() {}",
+            expect![[r#"
+                Program@0..29
+                  EnumItem@0..17
+                    Enum@0..4 "enum"
+                    Whitespace@4..5 " "
+                    Ident@5..10 "Color"
+                    Whitespace@10..11 " "
+                    VariantList@11..17
+                      OpenBrace@11..12 "{"
+                      Whitespace@12..13 " "
+                      Variant@13..17
+                        Ident@13..16 "Red"
+                        Whitespace@16..17 "\n"
+                  Function@17..29
+                    Fn@17..19 "fn"
+                    Whitespace@19..20 " "
+                    Ident@20..24 "main"
+                    ParamList@24..27
+                      OpenParen@24..25 "("
+                      CloseParen@25..26 ")"
+                      Whitespace@26..27 " "
+                    Block@27..29
+                      OpenBrace@27..28 "{"
+                      CloseBrace@28..29 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_test_attr() {
+        check_fn(
+            "#[test] fn f() {}",
+            expect![[r##"
+                Function@0..17
+                  Attr@0..8
+                    Hash@0..1 "#"
+                    OpenBracket@1..2 "["
+                    Ident@2..6 "test"
+                    CloseBracket@6..7 "]"
+                    Whitespace@7..8 " "
+                  Fn@8..10 "fn"
+                  Whitespace@10..11 " "
+                  Ident@11..12 "f"
+                  ParamList@12..15
+                    OpenParen@12..13 "("
+                    CloseParen@13..14 ")"
+                    Whitespace@14..15 " "
+                  Block@15..17
+                    OpenBrace@15..16 "{"
+                    CloseBrace@16..17 "}""##]],
+        );
+    }
+
+    #[test]
+    fn parse_attr_args() {
+        check_attr(
+            "#[deprecated(\"use g\", 2)]",
+            expect![[r##"
+                Attr@0..25
+                  Hash@0..1 "#"
+                  OpenBracket@1..2 "["
+                  Ident@2..12 "deprecated"
+                  ArgList@12..24
+                    OpenParen@12..13 "("
+                    Literal@13..20
+                      String@13..20 "\"use g\""
+                    Comma@20..21 ","
+                    Whitespace@21..22 " "
+                    Literal@22..23
+                      Int@22..23 "2"
+                    CloseParen@23..24 ")"
+                  CloseBracket@24..25 "]""##]],
+        );
+    }
+
+    #[test]
+    fn parse_attrs_before_items() {
+        check_program(
+            "#![no_std]\n#[a]\n#[b(1)]\nstruct S;\n#[c]",
+            expect![[r##"
+                Program@0..38
+                  Attr@0..11
+                    Hash@0..1 "#"
+                    Not@1..2 "!"
+                    OpenBracket@2..3 "["
+                    Ident@3..9 "no_std"
+                    CloseBracket@9..10 "]"
+                    Whitespace@10..11 "\n"
+                  StructItem@11..34
+                    Attr@11..16
+                      Hash@11..12 "#"
+                      OpenBracket@12..13 "["
+                      Ident@13..14 "a"
+                      CloseBracket@14..15 "]"
+                      Whitespace@15..16 "\n"
+                    Attr@16..24
+                      Hash@16..17 "#"
+                      OpenBracket@17..18 "["
+                      Ident@18..19 "b"
+                      ArgList@19..22
+                        OpenParen@19..20 "("
+                        Literal@20..21
+                          Int@20..21 "1"
+                        CloseParen@21..22 ")"
+                      CloseBracket@22..23 "]"
+                      Whitespace@23..24 "\n"
+                    Struct@24..30 "struct"
+                    Whitespace@30..31 " "
+                    Ident@31..32 "S"
+                    Semicolon@32..33 ";"
+                    Whitespace@33..34 "\n"
+                  Attr@34..38
+                    Hash@34..35 "#"
+                    OpenBracket@35..36 "["
+                    Ident@36..37 "c"
+                    CloseBracket@37..38 "]"
+                  ErrorNode@38..38"##]],
+        );
+    }
+
+    #[test]
+    fn parse_pub_fn() {
+        check_fn(
+            "pub fn f() {}",
+            expect![[r#"
+                Function@0..13
+                  Visibility@0..4
+                    Pub@0..3 "pub"
+                    Whitespace@3..4 " "
+                  Fn@4..6 "fn"
+                  Whitespace@6..7 " "
+                  Ident@7..8 "f"
+                  ParamList@8..11
+                    OpenParen@8..9 "("
+                    CloseParen@9..10 ")"
+                    Whitespace@10..11 " "
+                  Block@11..13
+                    OpenBrace@11..12 "{"
+                    CloseBrace@12..13 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_restricted_visibility() {
+        check_program(
+            "pub(crate) struct S {}\npub(super) enum E {}",
+            expect![[r#"
+                Program@0..43
+                  StructItem@0..23
+                    Visibility@0..11
+                      Pub@0..3 "pub"
+                      OpenParen@3..4 "("
+                      Crate@4..9 "crate"
+                      CloseParen@9..10 ")"
+                      Whitespace@10..11 " "
+                    Struct@11..17 "struct"
+                    Whitespace@17..18 " "
+                    Ident@18..19 "S"
+                    Whitespace@19..20 " "
+                    FieldList@20..23
+                      OpenBrace@20..21 "{"
+                      CloseBrace@21..22 "}"
+                      Whitespace@22..23 "\n"
+                  EnumItem@23..43
+                    Visibility@23..34
+                      Pub@23..26 "pub"
+                      OpenParen@26..27 "("
+                      Super@27..32 "super"
+                      CloseParen@32..33 ")"
+                      Whitespace@33..34 " "
+                    Enum@34..38 "enum"
+                    Whitespace@38..39 " "
+                    Ident@39..40 "E"
+                    Whitespace@40..41 " "
+                    VariantList@41..43
+                      OpenBrace@41..42 "{"
+                      CloseBrace@42..43 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_private_item() {
+        check_struct(
+            "struct S {}",
+            expect![[r#"
+                StructItem@0..11
+                  Struct@0..6 "struct"
+                  Whitespace@6..7 " "
+                  Ident@7..8 "S"
+                  Whitespace@8..9 " "
+                  FieldList@9..11
+                    OpenBrace@9..10 "{"
+                    CloseBrace@10..11 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_bad_visibility() {
+        check_program(
+            "pub(x) fn f() {}",
+            expect![[r#"
+                Program@0..16
+                  Function@0..16
+                    Visibility@0..7
+                      Pub@0..3 "pub"
+                      OpenParen@3..4 "("
+                      ErrorNode@4..5
+                        Ident@4..5 "x"
+                      CloseParen@5..6 ")"
+                      Whitespace@6..7 " "
+                    Fn@7..9 "fn"
+                    Whitespace@9..10 " "
+                    Ident@10..11 "f"
+                    ParamList@11..14
+                      OpenParen@11..12 "("
+                      CloseParen@12..13 ")"
+                      Whitespace@13..14 " "
+                    Block@14..16
+                      OpenBrace@14..15 "{"
+                      CloseBrace@15..16 "}""#]],
+        );
+    }
+}
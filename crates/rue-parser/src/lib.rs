@@ -1,14 +1,316 @@
-use parser::Parser;
-use program::parse_program;
 use rue_lexer::{Lexer, Token};
-use rue_syntax::SyntaxNode;
+use rue_syntax::{SyntaxKind, SyntaxNode};
 
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+
+mod block;
+mod expr;
+mod item;
+mod parse_error;
 mod parser;
 mod program;
+mod reparse;
+mod text_edit;
+mod ty;
+
+pub use expr::parse_expr;
+pub use item::parse_fn;
+pub use parse_error::*;
+pub use parser::Parser;
+pub use program::parse_program;
+pub use reparse::*;
+pub use text_edit::*;
+pub use ty::parse_type;
+
+#[derive(Debug, Clone)]
+pub struct Parse {
+    pub node: SyntaxNode,
+    pub errors: Vec<ParseError>,
+}
 
 pub fn parse_text(source: &str) -> SyntaxNode {
+    parse_text_with_errors(source).node
+}
+
+pub fn parse_text_with_errors(source: &str) -> Parse {
     let tokens: Vec<Token> = Lexer::new(source).collect();
     let mut parser = Parser::new(&tokens);
     parse_program(&mut parser);
     parser.build()
 }
+
+/// Parses a single expression, such as a line typed into a REPL.
+pub fn parse_expr_text(source: &str) -> Parse {
+    parse_fragment(source, parse_expr)
+}
+
+pub fn parse_type_text(source: &str) -> Parse {
+    parse_fragment(source, parse_type)
+}
+
+/// Parses part of a program on its own, wrapped in a [`SyntaxKind::Fragment`] node.
+/// Anything left over after it is an error.
+fn parse_fragment(source: &str, parse: fn(&mut Parser)) -> Parse {
+    let tokens: Vec<Token> = Lexer::new(source).collect();
+    let mut parser = Parser::new(&tokens);
+    parser.start(SyntaxKind::Fragment);
+    parse(&mut parser);
+    if !parser.at_eof() {
+        parser.error_rest("expected end of input".to_string());
+    }
+    parser.finish();
+    parser.build()
+}
+
+#[cfg(test)]
+mod tests {
+    use rowan::TextRange;
+    use rue_syntax::ast::{AstNode, Program, Type};
+
+    use super::*;
+
+    #[test]
+    fn parse_errors_have_spans() {
+        let parse = parse_text_with_errors("fn (");
+
+        assert_eq!(parse.node.text(), "fn (");
+        assert_eq!(
+            parse.errors,
+            [
+                ParseError::new(
+                    TextRange::new(3.into(), 4.into()),
+                    "expected identifier, found open parenthesis"
+                ),
+                ParseError::new(
+                    TextRange::empty(4.into()),
+                    "expected one of close parenthesis, open brace, close brace, found end of file"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_error_kinds() {
+        let kinds = |source: &str| -> Vec<SyntaxKind> {
+            parse_text_with_errors(source)
+                .node
+                .descendants_with_tokens()
+                .map(|element| element.kind())
+                .filter(|kind| matches!(kind, SyntaxKind::ErrorToken | SyntaxKind::ErrorNode))
+                .collect()
+        };
+
+        assert_eq!(kinds("@"), [SyntaxKind::ErrorNode, SyntaxKind::ErrorToken]);
+        assert_eq!(kinds("fn f() {} }"), [SyntaxKind::ErrorNode]);
+
+        let parse = parse_text_with_errors("fn f() { 1 @ }");
+        assert_eq!(
+            parse.errors,
+            [ParseError::new(
+                TextRange::new(11.into(), 12.into()),
+                "expected `;`, found unknown character"
+            )]
+        );
+    }
+
+    #[test]
+    fn parse_truncated_fn() {
+        let parse = parse_text_with_errors("fn main() {");
+
+        assert_eq!(parse.node.text(), "fn main() {");
+        assert_eq!(
+            parse.errors,
+            [
+                ParseError::new(
+                    TextRange::empty(11.into()),
+                    "expected close brace, found end of file"
+                ),
+                ParseError::new(TextRange::new(10.into(), 11.into()), "unclosed delimiter"),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_extra_close_brace() {
+        let parse = parse_text_with_errors("fn f() {}}\nfn g() {}");
+
+        assert_eq!(
+            parse.errors,
+            [ParseError::new(
+                TextRange::new(9.into(), 10.into()),
+                "unexpected closing delimiter"
+            )]
+        );
+        let program = Program::cast(parse.node).unwrap();
+        assert_eq!(program.functions().count(), 2);
+    }
+
+    #[test]
+    fn parse_missing_close_brace() {
+        let parse = parse_text_with_errors("fn f() {\n    let x = 1;\nfn g() {}");
+
+        assert_eq!(
+            parse.errors,
+            [
+                ParseError::new(
+                    TextRange::new(24.into(), 26.into()),
+                    "expected close brace, found `fn`"
+                ),
+                ParseError::new(TextRange::new(7.into(), 8.into()), "unclosed delimiter"),
+            ]
+        );
+        let program = Program::cast(parse.node).unwrap();
+        assert_eq!(program.functions().count(), 2);
+    }
+
+    #[test]
+    fn parse_errors_are_aggregated() {
+        let parse = parse_text_with_errors("fn 123");
+
+        assert_eq!(parse.errors.len(), 2);
+        assert_eq!(
+            parse.errors[0],
+            ParseError::new(
+                TextRange::new(3.into(), 6.into()),
+                "expected identifier, found integer literal"
+            )
+        );
+        assert_eq!(
+            parse.errors[1].message,
+            "expected one of open parenthesis, close parenthesis, open brace, close brace, found end of file"
+        );
+    }
+
+    #[test]
+    fn walk_typed_ast() {
+        let node = parse_text("fn add(a: Int, b: (Int, Int)) -> std::Int {}\nfn main() {}");
+        let program = Program::cast(node).unwrap();
+
+        let functions: Vec<_> = program.functions().collect();
+        assert_eq!(functions.len(), 2);
+
+        let add = &functions[0];
+        assert_eq!(add.name().unwrap().text(), "add");
+        assert!(add.body().is_some());
+
+        let params: Vec<_> = add.param_list().unwrap().params().collect();
+        assert_eq!(params.len(), 2);
+        assert_eq!(params[0].name().unwrap().text(), "a");
+        assert_eq!(params[1].name().unwrap().text(), "b");
+
+        let Some(Type::Tuple(tuple)) = params[1].ty() else {
+            panic!("expected a tuple type");
+        };
+        assert_eq!(tuple.types().count(), 2);
+
+        let Some(Type::Path(path)) = add.ret_type().unwrap().ty() else {
+            panic!("expected a path type");
+        };
+        let segments: Vec<_> = path
+            .segments()
+            .map(|token| token.text().to_string())
+            .collect();
+        assert_eq!(segments, ["std", "Int"]);
+
+        let main = &functions[1];
+        assert_eq!(main.name().unwrap().text(), "main");
+        assert_eq!(main.param_list().unwrap().params().count(), 0);
+        assert!(main.ret_type().is_none());
+    }
+
+    #[test]
+    fn typed_visibility() {
+        let node = parse_text("pub fn f() {}\nfn g() {}\npub(crate) struct S {}");
+        let program = Program::cast(node).unwrap();
+
+        let functions: Vec<_> = program.functions().collect();
+        let visibility = functions[0].visibility().unwrap();
+        assert!(visibility.restriction().is_none());
+        assert!(functions[1].visibility().is_none());
+
+        let s = program.structs().next().unwrap();
+        assert_eq!(s.name().unwrap().text(), "S");
+        let restriction = s.visibility().unwrap().restriction().unwrap();
+        assert_eq!(restriction.text(), "crate");
+    }
+
+    #[test]
+    fn typed_generics() {
+        let node = parse_text("struct S<T, U> {}\nfn f<T>(x: Vec<T>) {}");
+        let program = Program::cast(node).unwrap();
+
+        let s = program.structs().next().unwrap();
+        let names: Vec<String> = s
+            .generic_params()
+            .unwrap()
+            .params()
+            .map(|param| param.name().unwrap().to_string())
+            .collect();
+        assert_eq!(names, ["T", "U"]);
+
+        let f = program.functions().next().unwrap();
+        assert_eq!(f.generic_params().unwrap().params().count(), 1);
+        let param = f.param_list().unwrap().params().next().unwrap();
+        let Some(Type::Path(path)) = param.ty() else {
+            panic!("expected a path type");
+        };
+        assert_eq!(path.generic_args().unwrap().types().count(), 1);
+    }
+
+    #[test]
+    fn parse_standalone_expr() {
+        let parse = parse_expr_text(" 1 + f(x) ");
+        assert!(parse.errors.is_empty());
+        assert_eq!(
+            rue_syntax::to_sexpr(&parse.node),
+            r#"(Fragment (BinaryExpr (Literal (Int "1")) (Plus "+") (CallExpr (NameRef (Ident "f")) (ArgList (OpenParen "(") (NameRef (Ident "x")) (CloseParen ")")))))"#
+        );
+
+        let parse = parse_expr_text("a b");
+        assert_eq!(parse.node.text(), "a b");
+        assert_eq!(
+            parse.errors,
+            [ParseError::new(
+                TextRange::new(2.into(), 3.into()),
+                "expected end of input"
+            )]
+        );
+    }
+
+    #[test]
+    fn parse_standalone_type() {
+        let parse = parse_type_text("(Int, std::Bool)");
+        assert!(parse.errors.is_empty());
+
+        let ty = parse.node.first_child().and_then(Type::cast);
+        let Some(Type::Tuple(tuple)) = ty else {
+            panic!("expected a tuple type");
+        };
+        assert_eq!(tuple.types().count(), 2);
+    }
+
+    #[test]
+    fn sexpr() {
+        let node = parse_text("fn main() {}");
+        assert_eq!(
+            rue_syntax::to_sexpr(&node),
+            r#"(Program (Function (Fn "fn") (Ident "main") (ParamList (OpenParen "(") (CloseParen ")")) (Block (OpenBrace "{") (CloseBrace "}"))))"#
+        );
+
+        let node = parse_text("fn f() { a.b(1 + 2) }");
+        assert_eq!(
+            rue_syntax::to_sexpr(&node.first_child().unwrap().last_child().unwrap()),
+            r#"(Block (OpenBrace "{") (MethodCallExpr (NameRef (Ident "a")) (Dot ".") (Ident "b") (ArgList (OpenParen "(") (BinaryExpr (Literal (Int "1")) (Plus "+") (Literal (Int "2"))) (CloseParen ")"))) (CloseBrace "}"))"#
+        );
+    }
+
+    #[test]
+    fn sexpr_with_trivia() {
+        let node = parse_text("// main\nfn main() {}");
+        assert_eq!(
+            rue_syntax::to_sexpr_with_trivia(&node),
+            r#"(Program (Function (LineComment "// main") (Whitespace "\n") (Fn "fn") (Whitespace " ") (Ident "main") (ParamList (OpenParen "(") (CloseParen ")") (Whitespace " ")) (Block (OpenBrace "{") (CloseBrace "}"))))"#
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use rowan::TextRange;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    pub range: TextRange,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(range: TextRange, message: impl Into<String>) -> Self {
+        Self {
+            range,
+            message: message.into(),
+        }
+    }
+}
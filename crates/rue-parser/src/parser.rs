@@ -1,82 +1,361 @@
-use rowan::{GreenNodeBuilder, Language};
+use rowan::{Checkpoint, GreenNodeBuilder, Language, TextRange, TextSize};
 use rue_lexer::{Token, TokenKind};
 use rue_syntax::{RueLang, SyntaxKind, SyntaxNode};
 
+use crate::{Parse, ParseError};
+
 pub struct Parser<'a> {
-    tokens: Vec<(SyntaxKind, &'a str)>,
+    tokens: &'a [Token<'a>],
+    kinds: Vec<SyntaxKind>,
     pos: usize,
+    offset: TextSize,
     builder: GreenNodeBuilder<'static>,
-    errors: Vec<String>,
+    errors: Vec<ParseError>,
+    expected: Vec<SyntaxKind>,
+    expected_error: Option<usize>,
+    at_line_start: bool,
+    /// The ranges of the open delimiters which haven't been closed yet, or `None` for
+    /// one which was missing.
+    delimiters: Vec<Option<TextRange>>,
+    /// How many bytes of the current token were already consumed by
+    /// [`Parser::bump_split`].
+    split: usize,
 }
 
 impl<'a> Parser<'a> {
-    pub fn new(tokens: &[Token<'a>]) -> Self {
+    pub fn new(tokens: &'a [Token<'a>]) -> Self {
         Self {
-            tokens: tokens.iter().map(convert_token).collect(),
+            tokens,
+            kinds: tokens
+                .iter()
+                .map(|token| convert_kind(token.kind()))
+                .collect(),
             pos: 0,
+            offset: TextSize::default(),
             builder: GreenNodeBuilder::new(),
             errors: Vec::new(),
+            expected: Vec::new(),
+            expected_error: None,
+            at_line_start: true,
+            delimiters: Vec::new(),
+            split: 0,
         }
     }
 
-    pub fn build(self) -> SyntaxNode {
-        SyntaxNode::new_root(self.builder.finish())
+    pub fn build(self) -> Parse {
+        Parse {
+            node: SyntaxNode::new_root(self.builder.finish()),
+            errors: self.errors,
+        }
+    }
+
+    /// Whether every token has been consumed.
+    pub fn is_done(&self) -> bool {
+        self.pos == self.tokens.len()
+    }
+
+    /// The number of tokens consumed so far, used to check that a loop is making
+    /// progress.
+    pub fn pos(&self) -> usize {
+        self.pos
     }
 
     pub fn start(&mut self, kind: SyntaxKind) {
         self.builder.start_node(RueLang::kind_to_raw(kind));
     }
 
+    /// Marks the position of the next significant token, so that a node can later
+    /// be started there with [`Parser::start_at`]. Leading trivia is consumed first
+    /// so that it stays outside of the wrapping node, except for comments on their
+    /// own lines, which belong to the node after them.
+    pub fn checkpoint(&mut self) -> Checkpoint {
+        self.eat_trivia();
+        self.builder.checkpoint()
+    }
+
+    pub fn start_at(&mut self, checkpoint: Checkpoint, kind: SyntaxKind) {
+        self.builder
+            .start_node_at(checkpoint, RueLang::kind_to_raw(kind));
+    }
+
     pub fn finish(&mut self) {
         self.eat_trivia();
         self.builder.finish_node();
     }
 
     pub fn at(&mut self, kind: SyntaxKind) -> bool {
-        // TODO: Composite checks
-        self.peek() == kind
+        self.peek() == kind || composite_parts(kind).is_some_and(|parts| self.at_composite(parts))
+    }
+
+    /// Whether only trivia is left. There is no token for the end of the source, so
+    /// nothing can consume it, and loops must check for it to terminate.
+    pub fn at_eof(&mut self) -> bool {
+        self.peek() == SyntaxKind::Eof
     }
 
     pub fn expect(&mut self, kind: SyntaxKind) -> bool {
+        self.expect_recover(kind, &[])
+    }
+
+    /// Like [`Parser::expect`], but the offending token is left in place if it is in
+    /// the recovery set, so that the enclosing construct can still consume it.
+    pub fn expect_recover(&mut self, kind: SyntaxKind, recovery: &[SyntaxKind]) -> bool {
         if self.eat(kind) {
-            true
-        } else {
-            self.error(format!("expected {kind:?}"));
-            false
+            return true;
+        }
+
+        self.report_expected();
+        if !self.at_eof() && !self.at_any(recovery) {
+            self.bump_error();
+        }
+        false
+    }
+
+    /// Like [`Parser::expect_recover`], but remembers where the delimiter was opened so
+    /// that [`Parser::expect_close`] can point at it if it's never closed.
+    pub fn expect_open(&mut self, kind: SyntaxKind, recovery: &[SyntaxKind]) -> bool {
+        let found = self.expect_recover(kind, recovery);
+        let range = found.then(|| {
+            let len = TextSize::of(self.tokens[self.pos - 1].text());
+            TextRange::at(self.offset - len, len)
+        });
+        self.delimiters.push(range);
+        found
+    }
+
+    /// Closes the delimiter opened by the last call to [`Parser::expect_open`].
+    pub fn expect_close(&mut self, kind: SyntaxKind, recovery: &[SyntaxKind]) -> bool {
+        let open = self.delimiters.pop().flatten();
+        if self.expect_recover(kind, recovery) {
+            return true;
+        }
+
+        if let Some(range) = open {
+            self.errors
+                .push(ParseError::new(range, "unclosed delimiter"));
         }
+        false
+    }
+
+    pub fn at_any(&mut self, kinds: &[SyntaxKind]) -> bool {
+        kinds.iter().any(|&kind| self.at(kind))
     }
 
     pub fn eat(&mut self, kind: SyntaxKind) -> bool {
-        // TODO: Composite checks
+        if !self.expected.contains(&kind) {
+            self.expected.push(kind);
+        }
+
         if self.peek() == kind {
             self.bump();
             true
+        } else if composite_parts(kind).is_some_and(|parts| self.at_composite(parts)) {
+            self.bump_composite(kind);
+            true
         } else {
             false
         }
     }
 
-    fn error(&mut self, message: String) {
-        self.errors.push(message);
-        self.start(SyntaxKind::Error);
+    /// The kind of the `n`-th significant token from the current position, without
+    /// consuming any trivia along the way.
+    pub fn nth(&self, n: usize) -> SyntaxKind {
+        self.kinds[self.pos..]
+            .iter()
+            .copied()
+            .filter(|kind| !kind.is_trivia())
+            .nth(n)
+            .unwrap_or_default()
+    }
+
+    /// The text of the next significant token, or an empty string at the end of the
+    /// source.
+    pub fn peek_text(&self) -> &'a str {
+        let Some(index) =
+            (self.pos..self.kinds.len()).find(|&index| !self.kinds[index].is_trivia())
+        else {
+            return "";
+        };
+        let skip = if index == self.pos { self.split } else { 0 };
+        &self.tokens[index].text()[skip..]
+    }
+
+    /// Whether the next significant tokens are exactly the given kinds, with no trivia
+    /// between them. So `- >` is a minus and a greater than, rather than an arrow.
+    pub fn at_composite(&self, kinds: &[SyntaxKind]) -> bool {
+        kinds.iter().enumerate().all(|(n, &kind)| {
+            self.nth(n) == kind && (n + 1 == kinds.len() || self.nth_joined_with_next(n))
+        })
+    }
+
+    /// Whether the next significant token is directly followed by another, with no
+    /// trivia between them.
+    pub fn joined_with_next(&self) -> bool {
+        self.nth_joined_with_next(0)
+    }
+
+    fn nth_joined_with_next(&self, n: usize) -> bool {
+        let Some(index) = (self.pos..self.kinds.len())
+            .filter(|&index| !self.kinds[index].is_trivia())
+            .nth(n)
+        else {
+            return false;
+        };
+        self.kinds
+            .get(index + 1)
+            .is_some_and(|kind| !kind.is_trivia())
+    }
+
+    /// Consumes the tokens which make up a composite kind, such as `-` and `>` for
+    /// an arrow, and emits them as a single token of that kind.
+    pub fn bump_composite(&mut self, into: SyntaxKind) {
+        let Some(parts) = composite_parts(into) else {
+            panic!("{into:?} is not a composite kind");
+        };
+
+        self.eat_all_trivia();
+
+        let start = self.pos;
+        let mut remaining = parts.len();
+
+        while remaining > 0 && self.pos < self.tokens.len() {
+            if !self.kinds[self.pos].is_trivia() {
+                remaining -= 1;
+            }
+            self.pos += 1;
+        }
+
+        let text: String = self.tokens[start..self.pos]
+            .iter()
+            .map(|&token| token.text())
+            .collect();
+        let text = &text[std::mem::take(&mut self.split)..];
+        self.offset += TextSize::of(text);
+        self.reset_expected();
+        self.builder.token(RueLang::kind_to_raw(into), text);
+    }
+
+    /// Consumes only the first `len` bytes of the next token as the given kind, and
+    /// leaves the rest of it to be parsed as `rest`. This is how `&&` in a type is
+    /// split into two `&`.
+    pub fn bump_split(&mut self, kind: SyntaxKind, len: usize, rest: SyntaxKind) {
+        self.eat_all_trivia();
+        let Some(&token) = self.tokens.get(self.pos) else {
+            return;
+        };
+
+        let text = &token.text()[self.split..self.split + len];
+        self.builder.token(RueLang::kind_to_raw(kind), text);
+        self.offset += TextSize::of(text);
+        self.split += len;
+        self.kinds[self.pos] = rest;
+        self.at_line_start = false;
+        self.reset_expected();
+    }
+
+    /// Reports an error and wraps the offending token in an error node.
+    pub fn error(&mut self, message: String) {
+        self.push_error(message);
+        self.bump_error();
+    }
+
+    /// Reports an error and wraps every remaining token in a single error node.
+    pub fn error_rest(&mut self, message: String) {
+        self.push_error(message);
+        self.start(SyntaxKind::ErrorNode);
+        while !self.at_eof() {
+            self.bump();
+        }
+        self.finish();
+    }
+
+    /// Reports an error at the next token, and wraps everything the callback consumes
+    /// in an error node.
+    pub fn error_with(&mut self, message: String, parse: impl FnOnce(&mut Self)) {
+        self.push_error(message);
+        self.start(SyntaxKind::ErrorNode);
+        parse(self);
+        self.finish();
+    }
+
+    /// Reports an error without consuming anything, leaving an empty error node in
+    /// place of whatever was missing.
+    pub fn missing(&mut self, message: String) {
+        self.push_error(message);
+        self.start(SyntaxKind::ErrorNode);
+        self.builder.finish_node();
+    }
+
+    /// Reports every kind which was attempted at the current position. Repeated
+    /// failures before the parser makes progress are merged into a single error,
+    /// rather than cascading into one error per attempt.
+    fn report_expected(&mut self) {
+        let expected: Vec<&str> = self
+            .expected
+            .iter()
+            .map(|kind| kind.description())
+            .collect();
+        let found = self.peek().description();
+
+        let message = match expected.as_slice() {
+            [kind] => format!("expected {kind}, found {found}"),
+            kinds => format!("expected one of {}, found {found}", kinds.join(", ")),
+        };
+
+        if let Some(index) = self.expected_error {
+            self.errors[index].message = message;
+        } else {
+            self.expected_error = Some(self.errors.len());
+            self.push_error(message);
+        }
+    }
+
+    fn reset_expected(&mut self) {
+        self.expected.clear();
+        self.expected_error = None;
+    }
+
+    fn bump_error(&mut self) {
+        self.start(SyntaxKind::ErrorNode);
         self.bump();
         self.finish();
     }
 
+    /// Records an error at the next significant token, or at the end of the source.
+    fn push_error(&mut self, message: String) {
+        self.eat_trivia();
+
+        let mut offset = self.offset;
+        let mut len = TextSize::default();
+
+        for (index, &token) in self.tokens[self.pos..].iter().enumerate() {
+            let skip = if index == 0 { self.split } else { 0 };
+            len = TextSize::of(&token.text()[skip..]);
+            if !token.kind().is_trivia() {
+                break;
+            }
+            offset += len;
+            len = TextSize::default();
+        }
+
+        let range = TextRange::at(offset, len);
+        self.errors.push(ParseError::new(range, message));
+    }
+
     fn peek(&mut self) -> SyntaxKind {
         self.eat_trivia();
-        self.peek_raw()
+        self.nth(0)
     }
 
     fn bump(&mut self) -> SyntaxKind {
-        self.eat_trivia();
+        self.eat_all_trivia();
         self.consume_token()
     }
 
-    fn nth_raw(&self, index: usize) -> SyntaxKind {
-        self.tokens
+    pub fn nth_raw(&self, index: usize) -> SyntaxKind {
+        self.kinds
             .get(self.pos + index)
-            .map(|token| token.0)
+            .copied()
             .unwrap_or_default()
     }
 
@@ -84,41 +363,372 @@ impl<'a> Parser<'a> {
         self.nth_raw(0)
     }
 
+    /// Consumes trivia up to the next significant token, but stops before a comment
+    /// which starts its own line. That comment is left for the next node, so that it
+    /// is attached to what it describes rather than to whatever came before it.
     fn eat_trivia(&mut self) {
+        while self.peek_raw().is_trivia() {
+            let is_comment = matches!(
+                self.peek_raw(),
+                SyntaxKind::LineComment | SyntaxKind::BlockComment
+            );
+            if is_comment && self.at_line_start && self.nth(0) != SyntaxKind::Eof {
+                break;
+            }
+            self.consume_token();
+        }
+    }
+
+    fn eat_all_trivia(&mut self) {
         while self.peek_raw().is_trivia() {
             self.consume_token();
         }
     }
 
     fn consume_token(&mut self) -> SyntaxKind {
-        let Some(token) = self.tokens.get(self.pos) else {
+        let Some(&token) = self.tokens.get(self.pos) else {
             return SyntaxKind::Eof;
         };
-        self.builder.token(RueLang::kind_to_raw(token.0), token.1);
+        let kind = self.kinds[self.pos];
+        let text = &token.text()[std::mem::take(&mut self.split)..];
+        self.builder.token(RueLang::kind_to_raw(kind), text);
+        self.offset += TextSize::of(text);
         self.pos += 1;
-        token.0
+
+        if matches!(token.kind(), TokenKind::Whitespace { newlines } if newlines > 0) {
+            self.at_line_start = true;
+        } else if !kind.is_trivia() {
+            self.at_line_start = false;
+        }
+
+        if !kind.is_trivia() {
+            self.reset_expected();
+        }
+        kind
     }
 }
 
-fn convert_token<'a>(token: &Token<'a>) -> (SyntaxKind, &'a str) {
-    let kind = match token.kind() {
-        TokenKind::Unknown => SyntaxKind::Error,
-        TokenKind::Whitespace => SyntaxKind::Whitespace,
+/// The smaller tokens which can be combined to form the given kind.
+fn composite_parts(kind: SyntaxKind) -> Option<&'static [SyntaxKind]> {
+    use SyntaxKind::*;
+
+    Some(match kind {
+        ColonColon => &[Colon, Colon],
+        Arrow => &[Minus, GreaterThan],
+        EqualEquals => &[Equals, Equals],
+        NotEquals => &[Not, Equals],
+        LessThanEquals => &[LessThan, Equals],
+        GreaterThanEquals => &[GreaterThan, Equals],
+        PlusEquals => &[Plus, Equals],
+        MinusEquals => &[Minus, Equals],
+        StarEquals => &[Star, Equals],
+        SlashEquals => &[Slash, Equals],
+        PercentEquals => &[Percent, Equals],
+        AndAnd => &[Amp, Amp],
+        OrOr => &[Pipe, Pipe],
+        _ => return None,
+    })
+}
+
+/// Only the kinds are converted up front. The text is borrowed from the lexer's
+/// tokens, so that they don't need to be copied into a second buffer.
+fn convert_kind(kind: TokenKind) -> SyntaxKind {
+    let converted = match kind {
+        TokenKind::Unknown => SyntaxKind::ErrorToken,
+        TokenKind::Whitespace { .. } => SyntaxKind::Whitespace,
         TokenKind::BlockComment { is_terminated: _ } => SyntaxKind::BlockComment,
         TokenKind::LineComment => SyntaxKind::LineComment,
+        TokenKind::Shebang => SyntaxKind::Shebang,
 
-        TokenKind::String { is_terminated: _ } => SyntaxKind::String,
+        TokenKind::String { .. } => SyntaxKind::String,
+        TokenKind::Char { is_terminated: _ } => SyntaxKind::Char,
+        TokenKind::MultilineString { .. } => SyntaxKind::String,
+        TokenKind::ByteString { .. } => SyntaxKind::ByteString,
+        TokenKind::Byte { .. } => SyntaxKind::Byte,
+        TokenKind::StringFragment => SyntaxKind::StringFragment,
+        TokenKind::InterpStart => SyntaxKind::InterpStart,
+        TokenKind::InterpEnd => SyntaxKind::InterpEnd,
         TokenKind::Ident => SyntaxKind::Ident,
+        TokenKind::Int { .. } => SyntaxKind::Int,
+        TokenKind::Float => SyntaxKind::Float,
 
         TokenKind::Fn => SyntaxKind::Fn,
+        TokenKind::Let => SyntaxKind::Let,
+        TokenKind::Mut => SyntaxKind::Mut,
+        TokenKind::If => SyntaxKind::If,
+        TokenKind::Else => SyntaxKind::Else,
+        TokenKind::While => SyntaxKind::While,
+        TokenKind::For => SyntaxKind::For,
+        TokenKind::Return => SyntaxKind::Return,
+        TokenKind::Break => SyntaxKind::Break,
+        TokenKind::Continue => SyntaxKind::Continue,
+        TokenKind::Struct => SyntaxKind::Struct,
+        TokenKind::Enum => SyntaxKind::Enum,
+        TokenKind::Pub => SyntaxKind::Pub,
+        TokenKind::Crate => SyntaxKind::Crate,
+        TokenKind::Super => SyntaxKind::Super,
+        TokenKind::True => SyntaxKind::True,
+        TokenKind::False => SyntaxKind::False,
 
         TokenKind::OpenParen => SyntaxKind::OpenParen,
         TokenKind::CloseParen => SyntaxKind::CloseParen,
         TokenKind::OpenBrace => SyntaxKind::OpenBrace,
         TokenKind::CloseBrace => SyntaxKind::CloseBrace,
+        TokenKind::OpenBracket => SyntaxKind::OpenBracket,
+        TokenKind::CloseBracket => SyntaxKind::CloseBracket,
 
+        TokenKind::Hash => SyntaxKind::Hash,
+        TokenKind::Dot => SyntaxKind::Dot,
+        TokenKind::Comma => SyntaxKind::Comma,
+        TokenKind::Colon => SyntaxKind::Colon,
+        TokenKind::ColonColon => SyntaxKind::ColonColon,
+        TokenKind::Semicolon => SyntaxKind::Semicolon,
+
+        TokenKind::Arrow => SyntaxKind::Arrow,
+
+        TokenKind::Equals => SyntaxKind::Equals,
+        TokenKind::Not => SyntaxKind::Not,
+
+        TokenKind::EqualEquals => SyntaxKind::EqualEquals,
+        TokenKind::NotEquals => SyntaxKind::NotEquals,
+        TokenKind::LessThan => SyntaxKind::LessThan,
         TokenKind::GreaterThan => SyntaxKind::GreaterThan,
+        TokenKind::LessThanEquals => SyntaxKind::LessThanEquals,
+        TokenKind::GreaterThanEquals => SyntaxKind::GreaterThanEquals,
+
+        TokenKind::Plus => SyntaxKind::Plus,
         TokenKind::Minus => SyntaxKind::Minus,
+        TokenKind::Star => SyntaxKind::Star,
+        TokenKind::Slash => SyntaxKind::Slash,
+        TokenKind::Percent => SyntaxKind::Percent,
+
+        TokenKind::PlusEquals => SyntaxKind::PlusEquals,
+        TokenKind::MinusEquals => SyntaxKind::MinusEquals,
+        TokenKind::StarEquals => SyntaxKind::StarEquals,
+        TokenKind::SlashEquals => SyntaxKind::SlashEquals,
+        TokenKind::PercentEquals => SyntaxKind::PercentEquals,
+
+        TokenKind::Amp => SyntaxKind::Amp,
+        TokenKind::Pipe => SyntaxKind::Pipe,
+        TokenKind::Caret => SyntaxKind::Caret,
+        TokenKind::AndAnd => SyntaxKind::AndAnd,
+        TokenKind::OrOr => SyntaxKind::OrOr,
     };
-    (kind, token.text())
+    debug_assert_eq!(converted.is_trivia(), kind.is_trivia());
+    converted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use expect_test::{expect, Expect};
+    use rue_lexer::Lexer;
+
+    fn parse_arrow(p: &mut Parser) {
+        p.start(SyntaxKind::Program);
+        p.expect(SyntaxKind::Arrow);
+        p.finish();
+    }
+
+    crate::check!(check_arrow, parse_arrow);
+
+    #[test]
+    fn parse_arrow_token() {
+        check_arrow(
+            "->",
+            expect![[r#"
+                Program@0..2
+                  Arrow@0..2 "->""#]],
+        );
+    }
+
+    #[test]
+    fn parse_composite_arrow() {
+        let tokens = [
+            Token::new(TokenKind::Minus, "-", 0),
+            Token::new(TokenKind::GreaterThan, ">", 1),
+        ];
+        let mut parser = Parser::new(&tokens);
+        assert!(parser.at(SyntaxKind::Arrow));
+        parse_arrow(&mut parser);
+
+        let node = parser.build().node;
+        let raw_tree = format!("{:#?}", node);
+        expect![[r#"
+            Program@0..2
+              Arrow@0..2 "->"
+        "#]]
+        .assert_eq(&raw_tree);
+    }
+
+    #[test]
+    fn parse_spaced_composite_arrow() {
+        check_arrow(
+            "- >",
+            expect![[r#"
+                Program@0..2
+                  ErrorNode@0..2
+                    Minus@0..1 "-"
+                    Whitespace@1..2 " ""#]],
+        );
+    }
+
+    fn parse_wrapped(p: &mut Parser) {
+        p.start(SyntaxKind::Program);
+        let checkpoint = p.checkpoint();
+        p.expect(SyntaxKind::Ident);
+        p.expect(SyntaxKind::Ident);
+        p.start_at(checkpoint, SyntaxKind::Program);
+        p.finish();
+        p.finish();
+    }
+
+    crate::check!(check_wrapped, parse_wrapped);
+
+    #[test]
+    fn parse_checkpoint() {
+        check_wrapped(
+            "\n/* leading */ a b",
+            expect![[r#"
+                Program@0..18
+                  Whitespace@0..1 "\n"
+                  Program@1..18
+                    BlockComment@1..14 "/* leading */"
+                    Whitespace@14..15 " "
+                    Ident@15..16 "a"
+                    Whitespace@16..17 " "
+                    Ident@17..18 "b""#]],
+        );
+    }
+
+    fn parse_groups(p: &mut Parser, recovery: &[SyntaxKind]) {
+        p.start(SyntaxKind::Program);
+        while !p.at_eof() {
+            p.start(SyntaxKind::Program);
+            p.expect(SyntaxKind::OpenBrace);
+            p.expect_recover(SyntaxKind::Ident, recovery);
+            p.expect(SyntaxKind::CloseBrace);
+            p.finish();
+        }
+        p.finish();
+    }
+
+    fn parse_recovering_groups(p: &mut Parser) {
+        parse_groups(p, &[SyntaxKind::CloseBrace]);
+    }
+
+    crate::check!(check_recovering_groups, parse_recovering_groups);
+
+    #[test]
+    fn parse_recovery() {
+        check_recovering_groups(
+            "{} {x}",
+            expect![[r#"
+                Program@0..6
+                  Program@0..3
+                    OpenBrace@0..1 "{"
+                    CloseBrace@1..2 "}"
+                    Whitespace@2..3 " "
+                  Program@3..6
+                    OpenBrace@3..4 "{"
+                    Ident@4..5 "x"
+                    CloseBrace@5..6 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_recovery_errors() {
+        let tokens: Vec<Token> = Lexer::new("{} {x}").collect();
+        let mut parser = Parser::new(&tokens);
+        parse_recovering_groups(&mut parser);
+        assert_eq!(
+            parser.errors,
+            [ParseError::new(
+                TextRange::new(1.into(), 2.into()),
+                "expected identifier, found close brace"
+            )]
+        );
+
+        let mut parser = Parser::new(&tokens);
+        parse_groups(&mut parser, &[]);
+        assert_eq!(parser.errors.len(), 5);
+    }
+
+    #[test]
+    fn parse_error_range() {
+        let tokens: Vec<Token> = Lexer::new("{ 42 }").collect();
+        let mut parser = Parser::new(&tokens);
+        parse_recovering_groups(&mut parser);
+
+        let errors = parser.build().errors;
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].range, TextRange::new(2.into(), 4.into()));
+        assert_eq!(
+            errors[0].message,
+            "expected identifier, found integer literal"
+        );
+    }
+
+    #[test]
+    fn at_composite() {
+        let tokens = [
+            Token::new(TokenKind::Minus, "-", 0),
+            Token::new(TokenKind::GreaterThan, ">", 1),
+        ];
+        let parser = Parser::new(&tokens);
+        assert!(parser.at_composite(&[SyntaxKind::Minus, SyntaxKind::GreaterThan]));
+        assert!(!parser.at_composite(&[SyntaxKind::Minus, SyntaxKind::Equals]));
+
+        let tokens: Vec<Token> = Lexer::new("- /* gap */ > x").collect();
+        let parser = Parser::new(&tokens);
+        assert!(!parser.at_composite(&[SyntaxKind::Minus, SyntaxKind::GreaterThan]));
+    }
+
+    #[test]
+    fn joined_with_next() {
+        let tokens: Vec<Token> = Lexer::new(" a.b c").collect();
+        let mut parser = Parser::new(&tokens);
+        assert!(parser.joined_with_next());
+        parser.bump();
+        assert!(parser.joined_with_next());
+        parser.bump();
+        assert!(!parser.joined_with_next());
+        parser.bump();
+        assert!(!parser.joined_with_next());
+    }
+
+    #[test]
+    fn nth() {
+        let tokens: Vec<Token> = Lexer::new("a /* comment */\n// line\n b").collect();
+        let parser = Parser::new(&tokens);
+        assert_eq!(parser.nth(0), SyntaxKind::Ident);
+        assert_eq!(parser.nth(1), SyntaxKind::Ident);
+        assert_eq!(parser.nth(2), SyntaxKind::Eof);
+        assert_eq!(parser.nth_raw(1), SyntaxKind::Whitespace);
+        assert_eq!(parser.pos, 0);
+    }
+
+    #[test]
+    fn tokens_are_unchanged() {
+        let source = "// comment\nfn main(a: Int) -> Int {\n    let x = 0xFF + 1.5 * 'c';\n    /* block */ if !true { \"s\" } else { x @ a }\n}\n";
+        let tokens: Vec<Token> = Lexer::new(source).collect();
+        let node = crate::parse_text(source);
+
+        let expected: Vec<(SyntaxKind, &str)> = tokens
+            .iter()
+            .map(|token| (convert_kind(token.kind()), token.text()))
+            .collect();
+        let actual: Vec<(SyntaxKind, String)> = node
+            .descendants_with_tokens()
+            .filter_map(|element| element.into_token())
+            .map(|token| (token.kind(), token.text().to_string()))
+            .collect();
+
+        assert_eq!(expected.len(), actual.len());
+        for (expected, actual) in expected.iter().zip(&actual) {
+            assert_eq!(expected.0, actual.0);
+            assert_eq!(expected.1, actual.1);
+        }
+    }
 }
@@ -1,12 +1,48 @@
 use rue_syntax::SyntaxKind;
 
-use crate::parser::Parser;
+use crate::{
+    item::{at_inner_attr, item_start, parse_attr, parse_enum, parse_fn, parse_struct},
+    parser::Parser,
+};
 
 pub fn parse_program(p: &mut Parser) {
     p.start(SyntaxKind::Program);
+    while !p.at_eof() {
+        let start = p.pos();
+
+        if at_inner_attr(p) {
+            parse_attr(p);
+        } else {
+            parse_item(p);
+        }
+
+        // Every iteration must consume something, or a grammar function which gives
+        // up without consuming anything would stall the parser forever.
+        if p.pos() == start && !p.at_eof() {
+            p.error("expected item".to_string());
+        }
+    }
     p.finish();
 }
 
+fn parse_item(p: &mut Parser) {
+    match item_start(p) {
+        SyntaxKind::Fn => parse_fn(p),
+        SyntaxKind::Struct => parse_struct(p),
+        SyntaxKind::Enum => parse_enum(p),
+        _ if p.at(SyntaxKind::Hash) => {
+            while p.at(SyntaxKind::Hash) {
+                parse_attr(p);
+            }
+            if p.at_eof() {
+                p.missing("expected item after attributes".to_string());
+            }
+        }
+        SyntaxKind::CloseBrace => p.error("unexpected closing delimiter".to_string()),
+        _ => p.error("expected item".to_string()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -23,7 +59,7 @@ mod tests {
 
                 $parser(&mut parser);
 
-                let node = parser.build();
+                let node = parser.build().node;
                 let raw_tree = format!("{:#?}", node);
                 expected_tree.assert_eq(&raw_tree[0..(raw_tree.len() - 1)]);
             }
@@ -32,6 +68,14 @@ mod tests {
 
     check!(check_program, parse_program);
 
+    fn parse_ints(p: &mut Parser) {
+        p.start(SyntaxKind::Program);
+        while p.eat(SyntaxKind::Int) {}
+        p.finish();
+    }
+
+    check!(check_ints, parse_ints);
+
     #[test]
     fn parse_nothing() {
         check_program("", expect![[r#"Program@0..0"#]]);
@@ -49,4 +93,123 @@ mod tests {
                   Whitespace@35..36 "\n""#]],
         );
     }
+
+    #[test]
+    fn parse_int_tokens() {
+        check_ints(
+            "42 /* answer */ 007\n",
+            expect![[r#"
+                Program@0..20
+                  Int@0..2 "42"
+                  Whitespace@2..3 " "
+                  BlockComment@3..15 "/* answer */"
+                  Whitespace@15..16 " "
+                  Int@16..19 "007"
+                  Whitespace@19..20 "\n""#]],
+        );
+    }
+
+    #[test]
+    fn parse_functions() {
+        check_program(
+            "fn a() {}\nfn b() {}",
+            expect![[r#"
+                Program@0..19
+                  Function@0..10
+                    Fn@0..2 "fn"
+                    Whitespace@2..3 " "
+                    Ident@3..4 "a"
+                    ParamList@4..7
+                      OpenParen@4..5 "("
+                      CloseParen@5..6 ")"
+                      Whitespace@6..7 " "
+                    Block@7..10
+                      OpenBrace@7..8 "{"
+                      CloseBrace@8..9 "}"
+                      Whitespace@9..10 "\n"
+                  Function@10..19
+                    Fn@10..12 "fn"
+                    Whitespace@12..13 " "
+                    Ident@13..14 "b"
+                    ParamList@14..17
+                      OpenParen@14..15 "("
+                      CloseParen@15..16 ")"
+                      Whitespace@16..17 " "
+                    Block@17..19
+                      OpenBrace@17..18 "{"
+                      CloseBrace@18..19 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_unexpected_item() {
+        check_program(
+            "x fn a() {}",
+            expect![[r#"
+                Program@0..11
+                  ErrorNode@0..2
+                    Ident@0..1 "x"
+                    Whitespace@1..2 " "
+                  Function@2..11
+                    Fn@2..4 "fn"
+                    Whitespace@4..5 " "
+                    Ident@5..6 "a"
+                    ParamList@6..9
+                      OpenParen@6..7 "("
+                      CloseParen@7..8 ")"
+                      Whitespace@8..9 " "
+                    Block@9..11
+                      OpenBrace@9..10 "{"
+                      CloseBrace@10..11 "}""#]],
+        );
+    }
+
+    #[test]
+    fn parse_unknown_tokens() {
+        let source = "@".repeat(1000);
+        let tokens: Vec<Token> = Lexer::new(&source).collect();
+        let mut parser = Parser::new(&tokens);
+        parse_program(&mut parser);
+
+        let parse = parser.build();
+        assert_eq!(parse.node.text(), source.as_str());
+        assert_eq!(parse.errors.len(), 1000);
+    }
+
+    #[test]
+    fn parse_doc_comment() {
+        check_program(
+            "fn a() {}\n\n// Does nothing.\nfn b() {} // Also nothing.\n",
+            expect![[r#"
+                Program@0..55
+                  Function@0..11
+                    Fn@0..2 "fn"
+                    Whitespace@2..3 " "
+                    Ident@3..4 "a"
+                    ParamList@4..7
+                      OpenParen@4..5 "("
+                      CloseParen@5..6 ")"
+                      Whitespace@6..7 " "
+                    Block@7..11
+                      OpenBrace@7..8 "{"
+                      CloseBrace@8..9 "}"
+                      Whitespace@9..11 "\n\n"
+                  Function@11..55
+                    LineComment@11..27 "// Does nothing."
+                    Whitespace@27..28 "\n"
+                    Fn@28..30 "fn"
+                    Whitespace@30..31 " "
+                    Ident@31..32 "b"
+                    ParamList@32..35
+                      OpenParen@32..33 "("
+                      CloseParen@33..34 ")"
+                      Whitespace@34..35 " "
+                    Block@35..55
+                      OpenBrace@35..36 "{"
+                      CloseBrace@36..37 "}"
+                      Whitespace@37..38 " "
+                      LineComment@38..54 "// Also nothing."
+                      Whitespace@54..55 "\n""#]],
+        );
+    }
 }
@@ -0,0 +1,279 @@
+use rowan::{GreenToken, Language, NodeOrToken, TextRange, TextSize};
+use rue_lexer::{Lexer, Token, TokenKind};
+use rue_syntax::{RueLang, SyntaxKind, SyntaxNode, SyntaxToken};
+
+use crate::{
+    block::parse_block, parse_text_with_errors, parser::Parser, Parse, ParseError, TextEdit,
+};
+
+/// Applies an edit to a previous parse. Where possible, only the token or block
+/// containing the edit is parsed again, and the rest of the tree is shared with
+/// the old one. Otherwise, the whole source is parsed from scratch.
+pub fn reparse(old: &Parse, edit: &TextEdit) -> Parse {
+    reparse_token(old, edit)
+        .or_else(|| reparse_block(old, edit))
+        .unwrap_or_else(|| parse_text_with_errors(&edit.apply(&old.node.text().to_string())))
+}
+
+/// Relexes the token containing the edit, which works as long as it is still a
+/// single token of the same kind, and it doesn't join with its neighbors.
+fn reparse_token(old: &Parse, edit: &TextEdit) -> Option<Parse> {
+    let NodeOrToken::Token(token) = old.node.covering_element(edit.range) else {
+        return None;
+    };
+
+    // Errors point at tokens, so they could be affected by more than the length.
+    if token.kind() == SyntaxKind::ErrorToken || token.parent()?.kind() == SyntaxKind::ErrorNode {
+        return None;
+    }
+
+    // Newlines decide which node a comment is attached to.
+    if token.kind() == SyntaxKind::Whitespace {
+        return None;
+    }
+
+    let prev = token.prev_token();
+    let next = token.next_token();
+
+    let start = prev.as_ref().unwrap_or(&token).text_range().start();
+    let end = next.as_ref().unwrap_or(&token).text_range().end();
+    let relative = edit.range - token.text_range().start();
+
+    let mut new_text = token.text().to_string();
+    new_text.replace_range(std::ops::Range::<usize>::from(relative), &edit.new_text);
+
+    let old_tokens = lex(&old
+        .node
+        .text()
+        .slice(TextRange::new(start, end))
+        .to_string());
+    let new_tokens = lex(&format!(
+        "{}{new_text}{}",
+        prev.as_ref().map_or("", |prev| prev.text()),
+        next.as_ref().map_or("", |next| next.text()),
+    ));
+
+    // The edited token can change length, but its neighbors must stay the same.
+    let kinds = |tokens: &[(TokenKind, TextSize)]| -> Vec<TokenKind> {
+        tokens.iter().map(|(kind, _)| *kind).collect()
+    };
+    if kinds(&old_tokens) != kinds(&new_tokens)
+        || (prev.is_some() && old_tokens.first() != new_tokens.first())
+        || (next.is_some() && old_tokens.last() != new_tokens.last())
+    {
+        return None;
+    }
+
+    let green = GreenToken::new(RueLang::kind_to_raw(token.kind()), &new_text);
+    let node = SyntaxNode::new_root(token.replace_with(green));
+
+    let errors = old
+        .errors
+        .iter()
+        .map(|error| {
+            let mut range = error.range;
+            if range.start() >= edit.range.end() {
+                range = TextRange::new(edit.shift(range.start()), edit.shift(range.end()));
+            } else if range.end() >= edit.range.end() {
+                range = TextRange::new(range.start(), edit.shift(range.end()));
+            }
+            ParseError::new(range, error.message.clone())
+        })
+        .collect();
+
+    Some(Parse { node, errors })
+}
+
+/// Parses the innermost block containing the edit again, as long as its braces
+/// are still balanced so that it ends in the same place.
+fn reparse_block(old: &Parse, edit: &TextEdit) -> Option<Parse> {
+    let block = old
+        .node
+        .covering_element(edit.range)
+        .ancestors()
+        .find(|node| node.kind() == SyntaxKind::Block && is_inside_braces(node, edit.range))?;
+
+    let block_range = block.text_range();
+    let relative = edit.range - block_range.start();
+
+    let mut text = block.text().to_string();
+    text.replace_range(std::ops::Range::<usize>::from(relative), &edit.new_text);
+
+    let tokens: Vec<Token> = Lexer::new(&text).collect();
+    if !is_balanced(&tokens) {
+        return None;
+    }
+
+    let mut parser = Parser::new(&tokens);
+    parse_block(&mut parser);
+    if !parser.is_done() {
+        return None;
+    }
+
+    // Errors are kept in the order they were reported, so those from outside the
+    // block can't be merged with the new ones.
+    if old.errors.iter().any(|error| {
+        error.range.start() < block_range.start() || error.range.start() >= block_range.end()
+    }) {
+        return None;
+    }
+
+    let fragment = parser.build();
+    let node = SyntaxNode::new_root(block.replace_with(fragment.node.green().into_owned()));
+
+    let errors = fragment
+        .errors
+        .into_iter()
+        .map(|error| ParseError::new(error.range + block_range.start(), error.message))
+        .collect();
+
+    Some(Parse { node, errors })
+}
+
+/// Whether the range is after the block's opening brace and before its closing one.
+fn is_inside_braces(block: &SyntaxNode, range: TextRange) -> bool {
+    let brace = |kind| {
+        block
+            .children_with_tokens()
+            .filter_map(|element| element.into_token())
+            .find(|token: &SyntaxToken| token.kind() == kind)
+    };
+
+    let (Some(open), Some(close)) = (brace(SyntaxKind::OpenBrace), brace(SyntaxKind::CloseBrace))
+    else {
+        return false;
+    };
+
+    open.text_range().end() <= range.start() && range.end() <= close.text_range().start()
+}
+
+/// Whether the tokens are a single block, which starts with an opening brace and
+/// whose closing brace is the last significant token.
+fn is_balanced(tokens: &[Token]) -> bool {
+    let mut significant = tokens.iter().filter(|token| !token.kind().is_trivia());
+
+    if significant.next().map(|token| token.kind()) != Some(TokenKind::OpenBrace) {
+        return false;
+    }
+
+    let mut depth = 1;
+
+    for token in significant {
+        if depth == 0 {
+            return false;
+        }
+
+        match token.kind() {
+            TokenKind::OpenBrace => depth += 1,
+            TokenKind::CloseBrace => depth -= 1,
+            _ => {}
+        }
+    }
+
+    depth == 0
+}
+
+fn lex(text: &str) -> Vec<(TokenKind, TextSize)> {
+    Lexer::new(text)
+        .map(|token| (token.kind(), TextSize::of(token.text())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Checks that reparsing after the edit gives the same result as parsing the
+    /// edited source from scratch.
+    fn check(source: &str, range: (u32, u32), new_text: &str) -> Parse {
+        let edit = TextEdit::new(TextRange::new(range.0.into(), range.1.into()), new_text);
+        let old = parse_text_with_errors(source);
+        let reparsed = reparse(&old, &edit);
+        let expected = parse_text_with_errors(&edit.apply(source));
+
+        assert_eq!(
+            format!("{:#?}", reparsed.node),
+            format!("{:#?}", expected.node)
+        );
+        assert_eq!(reparsed.errors, expected.errors);
+        reparsed
+    }
+
+    #[test]
+    fn reparse_matches_full_parse() {
+        let source = "fn main() {\n    let x = 1;\n    x + 2\n}\n\nfn other(a: Int) { a }\n";
+
+        // Renaming an identifier.
+        check(source, (20, 21), "value");
+        // Editing a literal and whitespace.
+        check(source, (24, 25), "42");
+        check(source, (11, 16), "\n");
+        // Adding and removing statements.
+        check(source, (26, 26), "\n    let y = 2;");
+        check(source, (16, 27), "");
+        // Turning a statement into an error, and an edit across items.
+        check(source, (22, 23), "");
+        check(source, (32, 52), "");
+        // Changing a token into a keyword, and joining tokens.
+        check(source, (20, 21), "fn");
+        check(source, (32, 33), "");
+        // Unbalancing a block.
+        check(source, (32, 32), "{");
+    }
+
+    #[test]
+    fn reparse_comments() {
+        let source = "fn a() {}\n// b\nfn b() {\n    1\n    // c\n}";
+        check(source, (9, 10), " ");
+        check(source, (26, 31), " ");
+        check(source, (14, 14), "\n");
+    }
+
+    #[test]
+    fn reparse_with_errors() {
+        let source = "fn main() {\n    let x = ;\n}\nfn (";
+        check(source, (20, 20), "1");
+        check(source, (16, 17), "value");
+        check(source, (29, 29), "other");
+    }
+
+    #[test]
+    fn reparse_unclosed_delimiter() {
+        let source = "fn f() { if x { a } ";
+        let reparsed = check(source, (16, 17), "a b");
+        assert_eq!(reparsed.errors.len(), 3);
+    }
+
+    #[test]
+    fn reparse_token_only() {
+        let old = parse_text_with_errors("fn main() { value }");
+        let edit = TextEdit::new(TextRange::new(12.into(), 17.into()), "other");
+        assert!(reparse_token(&old, &edit).is_some());
+
+        let edit = TextEdit::new(TextRange::new(12.into(), 17.into()), "a b");
+        assert!(reparse_token(&old, &edit).is_none());
+
+        // Identifiers outside of blocks can change length.
+        let edit = TextEdit::new(TextRange::new(3.into(), 7.into()), "mains");
+        let reparsed = reparse_token(&old, &edit).unwrap();
+        assert_eq!(reparsed.node.text(), "fn mains() { value }");
+
+        // But not by joining with their neighbors.
+        let edit = TextEdit::new(TextRange::new(3.into(), 7.into()), "main(");
+        assert!(reparse_token(&old, &edit).is_none());
+    }
+
+    #[test]
+    fn reparse_shares_unchanged_items() {
+        let source = "fn main() { 1 + 2 }\nfn other() { 3 }";
+        let old = parse_text_with_errors(source);
+        let edit = TextEdit::new(TextRange::new(14.into(), 15.into()), "*");
+
+        let new = reparse_block(&old, &edit).unwrap();
+        let old_item = old.node.children().nth(1).unwrap();
+        let new_item = new.node.children().nth(1).unwrap();
+
+        assert_eq!(new.node.text(), "fn main() { 1 * 2 }\nfn other() { 3 }");
+        assert!(std::ptr::eq(&*old_item.green(), &*new_item.green()));
+    }
+}
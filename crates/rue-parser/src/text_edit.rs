@@ -0,0 +1,98 @@
+use std::fmt;
+
+use rowan::{TextRange, TextSize};
+use rue_syntax::SyntaxNode;
+
+use crate::parse_text;
+
+/// Replaces a range of the source with new text. An empty range is an insertion,
+/// and empty new text is a deletion.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TextEdit {
+    pub range: TextRange,
+    pub new_text: String,
+}
+
+impl TextEdit {
+    pub fn new(range: TextRange, new_text: impl Into<String>) -> Self {
+        Self {
+            range,
+            new_text: new_text.into(),
+        }
+    }
+
+    pub fn apply(&self, text: &str) -> String {
+        let mut result = text.to_string();
+        result.replace_range(std::ops::Range::<usize>::from(self.range), &self.new_text);
+        result
+    }
+
+    /// Moves an offset after the edit to where the same text is afterwards.
+    pub(crate) fn shift(&self, offset: TextSize) -> TextSize {
+        offset + TextSize::of(self.new_text.as_str()) - self.range.len()
+    }
+}
+
+/// The range of a replacement was past the end of the source, or split a character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InvalidRange(pub TextRange);
+
+impl fmt::Display for InvalidRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is not a valid range of the source", self.0)
+    }
+}
+
+/// Replaces a range of the source of the tree containing the node, and parses the
+/// result again from scratch. The range is relative to the start of the source.
+pub fn replace_range(
+    node: &SyntaxNode,
+    range: TextRange,
+    text: &str,
+) -> Result<SyntaxNode, InvalidRange> {
+    let root = node.ancestors().last().unwrap_or_else(|| node.clone());
+    let source = root.text().to_string();
+
+    let is_valid = range.end() <= TextSize::of(source.as_str())
+        && source.is_char_boundary(range.start().into())
+        && source.is_char_boundary(range.end().into());
+    if !is_valid {
+        return Err(InvalidRange(range));
+    }
+
+    Ok(parse_text(&TextEdit::new(range, text).apply(&source)))
+}
+
+#[cfg(test)]
+mod tests {
+    use rue_syntax::ast::{AstNode, Program};
+
+    use super::*;
+
+    #[test]
+    fn replace_ident() {
+        let node = parse_text("fn main() {\n    foo(1)\n}");
+        let name = node
+            .descendants_with_tokens()
+            .filter_map(|element| element.into_token())
+            .find(|token| token.text() == "foo")
+            .unwrap();
+
+        let new = replace_range(&name.parent().unwrap(), name.text_range(), "bar").unwrap();
+        assert_eq!(new.text(), "fn main() {\n    bar(1)\n}");
+
+        let program = Program::cast(new).unwrap();
+        let main = program.functions().next().unwrap();
+        assert!(main.body().unwrap().tail_expr().is_some());
+    }
+
+    #[test]
+    fn replace_invalid_range() {
+        let node = parse_text("fn λ() {}");
+        let range = TextRange::new(4.into(), 5.into());
+        assert_eq!(replace_range(&node, range, "x"), Err(InvalidRange(range)));
+
+        let range = TextRange::new(8.into(), 20.into());
+        assert_eq!(replace_range(&node, range, "x"), Err(InvalidRange(range)));
+    }
+}
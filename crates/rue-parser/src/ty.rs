@@ -0,0 +1,351 @@
+use rue_syntax::SyntaxKind;
+
+use crate::parser::Parser;
+
+const TYPE_RECOVERY: &[SyntaxKind] = &[
+    SyntaxKind::Comma,
+    SyntaxKind::CloseParen,
+    SyntaxKind::GreaterThan,
+    SyntaxKind::OpenBrace,
+    SyntaxKind::CloseBrace,
+    SyntaxKind::Equals,
+    SyntaxKind::Semicolon,
+];
+
+pub fn at_type(p: &mut Parser) -> bool {
+    p.at_any(&[
+        SyntaxKind::Ident,
+        SyntaxKind::OpenParen,
+        SyntaxKind::Amp,
+        SyntaxKind::AndAnd,
+    ])
+}
+
+pub fn parse_type(p: &mut Parser) {
+    if !at_type(p) {
+        p.expect_recover(SyntaxKind::Ident, TYPE_RECOVERY);
+        return;
+    }
+
+    if p.at(SyntaxKind::OpenParen) {
+        parse_paren_type(p);
+        return;
+    }
+
+    if p.at_any(&[SyntaxKind::Amp, SyntaxKind::AndAnd]) {
+        parse_ref_type(p);
+        return;
+    }
+
+    p.start(SyntaxKind::PathType);
+    p.expect(SyntaxKind::Ident);
+    while p.eat(SyntaxKind::ColonColon) {
+        p.expect_recover(SyntaxKind::Ident, TYPE_RECOVERY);
+    }
+    if p.at(SyntaxKind::LessThan) {
+        parse_generic_args(p);
+    }
+    p.finish();
+}
+
+/// Parses the type arguments of a path, such as `<Int, T>`. Since `>>` is lexed as
+/// two tokens, nested arguments like `Vec<Vec<T>>` close without any splitting.
+fn parse_generic_args(p: &mut Parser) {
+    p.start(SyntaxKind::GenericArgList);
+    p.expect(SyntaxKind::LessThan);
+
+    while !p.at_eof() && !p.at(SyntaxKind::GreaterThan) {
+        if at_type(p) {
+            parse_type(p);
+        } else if p.at_any(TYPE_RECOVERY) {
+            break;
+        } else {
+            // Anything else is skipped, so that the list still ends at its `>`.
+            p.error("expected type".to_string());
+        }
+
+        if !p.at(SyntaxKind::GreaterThan) {
+            p.expect_recover(SyntaxKind::Comma, TYPE_RECOVERY);
+        }
+    }
+
+    p.expect_recover(SyntaxKind::GreaterThan, TYPE_RECOVERY);
+    p.finish();
+}
+
+/// Parses a reference type such as `&T` or `&mut T`. A `&&` is split in two, so that
+/// `&&T` is a reference to a reference.
+fn parse_ref_type(p: &mut Parser) {
+    p.start(SyntaxKind::RefType);
+    if p.at(SyntaxKind::AndAnd) {
+        p.bump_split(SyntaxKind::Amp, 1, SyntaxKind::Amp);
+    } else {
+        p.expect(SyntaxKind::Amp);
+    }
+    p.eat(SyntaxKind::Mut);
+    parse_type(p);
+    p.finish();
+}
+
+/// Parses either a tuple type such as `(A, B)`, or a single type in parentheses.
+/// A trailing comma makes a one element tuple, as in `(A,)`.
+fn parse_paren_type(p: &mut Parser) {
+    let checkpoint = p.checkpoint();
+    p.expect(SyntaxKind::OpenParen);
+
+    let mut is_tuple = true;
+
+    if !p.at(SyntaxKind::CloseParen) {
+        parse_type(p);
+        is_tuple = false;
+
+        while p.eat(SyntaxKind::Comma) {
+            is_tuple = true;
+            if p.at(SyntaxKind::CloseParen) {
+                break;
+            }
+            parse_type(p);
+        }
+    }
+
+    p.expect_recover(SyntaxKind::CloseParen, TYPE_RECOVERY);
+
+    let kind = if is_tuple {
+        SyntaxKind::TupleType
+    } else {
+        SyntaxKind::ParenType
+    };
+    p.start_at(checkpoint, kind);
+    p.finish();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use expect_test::{expect, Expect};
+    use rue_lexer::{Lexer, Token};
+
+    crate::check!(check_type, parse_type);
+
+    #[test]
+    fn parse_name_type() {
+        check_type(
+            "Int",
+            expect![[r#"
+                PathType@0..3
+                  Ident@0..3 "Int""#]],
+        );
+    }
+
+    #[test]
+    fn parse_path_type() {
+        check_type(
+            "a::b::C",
+            expect![[r#"
+                PathType@0..7
+                  Ident@0..1 "a"
+                  ColonColon@1..3 "::"
+                  Ident@3..4 "b"
+                  ColonColon@4..6 "::"
+                  Ident@6..7 "C""#]],
+        );
+    }
+
+    #[test]
+    fn parse_trailing_path_separator() {
+        check_type(
+            "a::",
+            expect![[r#"
+                PathType@0..3
+                  Ident@0..1 "a"
+                  ColonColon@1..3 "::""#]],
+        );
+
+        let tokens: Vec<Token> = Lexer::new("a::").collect();
+        let mut parser = Parser::new(&tokens);
+        parse_type(&mut parser);
+        assert_eq!(
+            parser.build().errors[0].message,
+            "expected identifier, found end of file"
+        );
+    }
+
+    #[test]
+    fn parse_unit_type() {
+        check_type(
+            "()",
+            expect![[r#"
+                TupleType@0..2
+                  OpenParen@0..1 "("
+                  CloseParen@1..2 ")""#]],
+        );
+    }
+
+    #[test]
+    fn parse_tuple_type() {
+        check_type(
+            "(Int, Int)",
+            expect![[r#"
+                TupleType@0..10
+                  OpenParen@0..1 "("
+                  PathType@1..4
+                    Ident@1..4 "Int"
+                  Comma@4..5 ","
+                  Whitespace@5..6 " "
+                  PathType@6..9
+                    Ident@6..9 "Int"
+                  CloseParen@9..10 ")""#]],
+        );
+    }
+
+    #[test]
+    fn parse_paren_type() {
+        check_type(
+            "(Int)",
+            expect![[r#"
+                ParenType@0..5
+                  OpenParen@0..1 "("
+                  PathType@1..4
+                    Ident@1..4 "Int"
+                  CloseParen@4..5 ")""#]],
+        );
+    }
+
+    #[test]
+    fn parse_single_tuple_type() {
+        check_type(
+            "(Int,)",
+            expect![[r#"
+                TupleType@0..6
+                  OpenParen@0..1 "("
+                  PathType@1..4
+                    Ident@1..4 "Int"
+                  Comma@4..5 ","
+                  CloseParen@5..6 ")""#]],
+        );
+    }
+
+    #[test]
+    fn parse_unclosed_tuple_type() {
+        check_type(
+            "(Int, Int {",
+            expect![[r#"
+                TupleType@0..10
+                  OpenParen@0..1 "("
+                  PathType@1..4
+                    Ident@1..4 "Int"
+                  Comma@4..5 ","
+                  Whitespace@5..6 " "
+                  PathType@6..10
+                    Ident@6..9 "Int"
+                    Whitespace@9..10 " ""#]],
+        );
+    }
+
+    #[test]
+    fn parse_nested_generic_type() {
+        check_type(
+            "Vec<Vec<T>>",
+            expect![[r#"
+                PathType@0..11
+                  Ident@0..3 "Vec"
+                  GenericArgList@3..11
+                    LessThan@3..4 "<"
+                    PathType@4..10
+                      Ident@4..7 "Vec"
+                      GenericArgList@7..10
+                        LessThan@7..8 "<"
+                        PathType@8..9
+                          Ident@8..9 "T"
+                        GreaterThan@9..10 ">"
+                    GreaterThan@10..11 ">""#]],
+        );
+    }
+
+    #[test]
+    fn parse_unclosed_generic_type() {
+        let tokens: Vec<Token> = Lexer::new("Map<K, V").collect();
+        let mut parser = Parser::new(&tokens);
+        parse_type(&mut parser);
+        assert_eq!(
+            parser.build().errors[0].message,
+            "expected one of `::`, `,`, `>`, found end of file"
+        );
+    }
+
+    #[test]
+    fn parse_invalid_generic_arg() {
+        check_type(
+            "Vec<1>",
+            expect![[r#"
+                PathType@0..6
+                  Ident@0..3 "Vec"
+                  GenericArgList@3..6
+                    LessThan@3..4 "<"
+                    ErrorNode@4..5
+                      Int@4..5 "1"
+                    GreaterThan@5..6 ">""#]],
+        );
+
+        let tokens: Vec<Token> = Lexer::new("Vec<1, T>").collect();
+        let mut parser = Parser::new(&tokens);
+        parse_type(&mut parser);
+        let errors = parser.build().errors;
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].message, "expected type");
+    }
+
+    #[test]
+    fn parse_ref_type() {
+        check_type(
+            "&Int",
+            expect![[r#"
+                RefType@0..4
+                  Amp@0..1 "&"
+                  PathType@1..4
+                    Ident@1..4 "Int""#]],
+        );
+    }
+
+    #[test]
+    fn parse_mut_ref_type() {
+        check_type(
+            "&mut Int",
+            expect![[r#"
+                RefType@0..8
+                  Amp@0..1 "&"
+                  Mut@1..4 "mut"
+                  Whitespace@4..5 " "
+                  PathType@5..8
+                    Ident@5..8 "Int""#]],
+        );
+    }
+
+    #[test]
+    fn parse_double_ref_type() {
+        check_type(
+            "&&Int",
+            expect![[r#"
+                RefType@0..5
+                  Amp@0..1 "&"
+                  RefType@1..5
+                    Amp@1..2 "&"
+                    PathType@2..5
+                      Ident@2..5 "Int""#]],
+        );
+        check_type(
+            "&& mut Int",
+            expect![[r#"
+                RefType@0..10
+                  Amp@0..1 "&"
+                  RefType@1..10
+                    Amp@1..2 "&"
+                    Whitespace@2..3 " "
+                    Mut@3..6 "mut"
+                    Whitespace@6..7 " "
+                    PathType@7..10
+                      Ident@7..10 "Int""#]],
+        );
+    }
+}
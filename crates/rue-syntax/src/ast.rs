@@ -0,0 +1,531 @@
+use rowan::NodeOrToken;
+
+use crate::{SyntaxKind, SyntaxNode, SyntaxToken};
+
+pub trait AstNode: Sized {
+    fn cast(node: SyntaxNode) -> Option<Self>;
+    fn syntax(&self) -> &SyntaxNode;
+}
+
+macro_rules! ast_node {
+    ($name:ident) => {
+        ast_node!($name, $name);
+    };
+    ($name:ident, $kind:ident) => {
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub struct $name(SyntaxNode);
+
+        impl AstNode for $name {
+            fn cast(node: SyntaxNode) -> Option<Self> {
+                (node.kind() == SyntaxKind::$kind).then(|| Self(node))
+            }
+
+            fn syntax(&self) -> &SyntaxNode {
+                &self.0
+            }
+        }
+    };
+}
+
+ast_node!(Program);
+ast_node!(Visibility);
+ast_node!(Function);
+ast_node!(Struct, StructItem);
+ast_node!(GenericParamList);
+ast_node!(TypeParam);
+ast_node!(ParamList);
+ast_node!(Param);
+ast_node!(RetType);
+ast_node!(Block);
+ast_node!(ExprStmt);
+ast_node!(LetStmt);
+ast_node!(Literal);
+ast_node!(NameRef);
+ast_node!(ParenExpr);
+ast_node!(PrefixExpr);
+ast_node!(BinaryExpr);
+ast_node!(CallExpr);
+ast_node!(ArgList);
+ast_node!(FieldExpr);
+ast_node!(MethodCallExpr);
+ast_node!(IfExpr);
+ast_node!(WhileExpr);
+ast_node!(InterpString);
+ast_node!(ReturnExpr);
+ast_node!(BreakExpr);
+ast_node!(ContinueExpr);
+ast_node!(PathType);
+ast_node!(GenericArgList);
+ast_node!(TupleType);
+ast_node!(ParenType);
+ast_node!(RefType);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Expr {
+    Literal(Literal),
+    NameRef(NameRef),
+    Paren(ParenExpr),
+    Prefix(PrefixExpr),
+    Binary(BinaryExpr),
+    Call(CallExpr),
+    Field(FieldExpr),
+    MethodCall(MethodCallExpr),
+    If(IfExpr),
+    While(WhileExpr),
+    InterpString(InterpString),
+    Return(ReturnExpr),
+    Break(BreakExpr),
+    Continue(ContinueExpr),
+}
+
+impl AstNode for Expr {
+    fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::Literal => Some(Self::Literal(Literal(node))),
+            SyntaxKind::NameRef => Some(Self::NameRef(NameRef(node))),
+            SyntaxKind::ParenExpr => Some(Self::Paren(ParenExpr(node))),
+            SyntaxKind::PrefixExpr => Some(Self::Prefix(PrefixExpr(node))),
+            SyntaxKind::BinaryExpr => Some(Self::Binary(BinaryExpr(node))),
+            SyntaxKind::CallExpr => Some(Self::Call(CallExpr(node))),
+            SyntaxKind::FieldExpr => Some(Self::Field(FieldExpr(node))),
+            SyntaxKind::MethodCallExpr => Some(Self::MethodCall(MethodCallExpr(node))),
+            SyntaxKind::IfExpr => Some(Self::If(IfExpr(node))),
+            SyntaxKind::WhileExpr => Some(Self::While(WhileExpr(node))),
+            SyntaxKind::InterpString => Some(Self::InterpString(InterpString(node))),
+            SyntaxKind::ReturnExpr => Some(Self::Return(ReturnExpr(node))),
+            SyntaxKind::BreakExpr => Some(Self::Break(BreakExpr(node))),
+            SyntaxKind::ContinueExpr => Some(Self::Continue(ContinueExpr(node))),
+            _ => None,
+        }
+    }
+
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            Self::Literal(expr) => expr.syntax(),
+            Self::NameRef(expr) => expr.syntax(),
+            Self::Paren(expr) => expr.syntax(),
+            Self::Prefix(expr) => expr.syntax(),
+            Self::Binary(expr) => expr.syntax(),
+            Self::Call(expr) => expr.syntax(),
+            Self::Field(expr) => expr.syntax(),
+            Self::MethodCall(expr) => expr.syntax(),
+            Self::If(expr) => expr.syntax(),
+            Self::While(expr) => expr.syntax(),
+            Self::InterpString(expr) => expr.syntax(),
+            Self::Return(expr) => expr.syntax(),
+            Self::Break(expr) => expr.syntax(),
+            Self::Continue(expr) => expr.syntax(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Stmt {
+    Expr(ExprStmt),
+    Let(LetStmt),
+}
+
+impl AstNode for Stmt {
+    fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::ExprStmt => Some(Self::Expr(ExprStmt(node))),
+            SyntaxKind::LetStmt => Some(Self::Let(LetStmt(node))),
+            _ => None,
+        }
+    }
+
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            Self::Expr(stmt) => stmt.syntax(),
+            Self::Let(stmt) => stmt.syntax(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LiteralKind {
+    Int,
+    Float,
+    String,
+    Char,
+    ByteString,
+    Byte,
+    Bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Type {
+    Path(PathType),
+    Tuple(TupleType),
+    Paren(ParenType),
+    Ref(RefType),
+}
+
+impl AstNode for Type {
+    fn cast(node: SyntaxNode) -> Option<Self> {
+        match node.kind() {
+            SyntaxKind::PathType => Some(Self::Path(PathType(node))),
+            SyntaxKind::TupleType => Some(Self::Tuple(TupleType(node))),
+            SyntaxKind::ParenType => Some(Self::Paren(ParenType(node))),
+            SyntaxKind::RefType => Some(Self::Ref(RefType(node))),
+            _ => None,
+        }
+    }
+
+    fn syntax(&self) -> &SyntaxNode {
+        match self {
+            Self::Path(ty) => ty.syntax(),
+            Self::Tuple(ty) => ty.syntax(),
+            Self::Paren(ty) => ty.syntax(),
+            Self::Ref(ty) => ty.syntax(),
+        }
+    }
+}
+
+impl Program {
+    pub fn functions(&self) -> impl Iterator<Item = Function> {
+        children(&self.0)
+    }
+
+    pub fn structs(&self) -> impl Iterator<Item = Struct> {
+        children(&self.0)
+    }
+}
+
+impl Visibility {
+    /// The `crate` or `super` in `pub(crate)` or `pub(super)`, if the visibility
+    /// is restricted.
+    pub fn restriction(&self) -> Option<SyntaxToken> {
+        token(&self.0, SyntaxKind::Crate).or_else(|| token(&self.0, SyntaxKind::Super))
+    }
+}
+
+impl Function {
+    /// The visibility of the function, which is private if there is none.
+    pub fn visibility(&self) -> Option<Visibility> {
+        child(&self.0)
+    }
+
+    pub fn name(&self) -> Option<SyntaxToken> {
+        token(&self.0, SyntaxKind::Ident)
+    }
+
+    pub fn generic_params(&self) -> Option<GenericParamList> {
+        child(&self.0)
+    }
+
+    pub fn param_list(&self) -> Option<ParamList> {
+        child(&self.0)
+    }
+
+    pub fn ret_type(&self) -> Option<RetType> {
+        child(&self.0)
+    }
+
+    pub fn body(&self) -> Option<Block> {
+        child(&self.0)
+    }
+}
+
+impl Struct {
+    pub fn visibility(&self) -> Option<Visibility> {
+        child(&self.0)
+    }
+
+    pub fn name(&self) -> Option<SyntaxToken> {
+        token(&self.0, SyntaxKind::Ident)
+    }
+
+    pub fn generic_params(&self) -> Option<GenericParamList> {
+        child(&self.0)
+    }
+}
+
+impl GenericParamList {
+    pub fn params(&self) -> impl Iterator<Item = TypeParam> {
+        children(&self.0)
+    }
+}
+
+impl TypeParam {
+    pub fn name(&self) -> Option<SyntaxToken> {
+        token(&self.0, SyntaxKind::Ident)
+    }
+}
+
+impl ParamList {
+    pub fn params(&self) -> impl Iterator<Item = Param> {
+        children(&self.0)
+    }
+}
+
+impl Param {
+    pub fn name(&self) -> Option<SyntaxToken> {
+        token(&self.0, SyntaxKind::Ident)
+    }
+
+    pub fn ty(&self) -> Option<Type> {
+        child(&self.0)
+    }
+}
+
+impl RetType {
+    pub fn ty(&self) -> Option<Type> {
+        child(&self.0)
+    }
+}
+
+impl Block {
+    pub fn stmts(&self) -> impl Iterator<Item = Stmt> {
+        children(&self.0)
+    }
+
+    /// The expression at the end of the block, which determines its value.
+    pub fn tail_expr(&self) -> Option<Expr> {
+        child(&self.0)
+    }
+}
+
+impl ExprStmt {
+    pub fn expr(&self) -> Option<Expr> {
+        child(&self.0)
+    }
+}
+
+impl LetStmt {
+    pub fn name(&self) -> Option<SyntaxToken> {
+        token(&self.0, SyntaxKind::Ident)
+    }
+
+    pub fn ty(&self) -> Option<Type> {
+        child(&self.0)
+    }
+
+    pub fn initializer(&self) -> Option<Expr> {
+        child(&self.0)
+    }
+}
+
+impl Literal {
+    pub fn token(&self) -> Option<SyntaxToken> {
+        self.0
+            .children_with_tokens()
+            .filter_map(|element| element.into_token())
+            .find(|token| !token.kind().is_trivia())
+    }
+
+    pub fn kind(&self) -> Option<LiteralKind> {
+        Some(match self.token()?.kind() {
+            SyntaxKind::Int => LiteralKind::Int,
+            SyntaxKind::Float => LiteralKind::Float,
+            SyntaxKind::String => LiteralKind::String,
+            SyntaxKind::Char => LiteralKind::Char,
+            SyntaxKind::ByteString => LiteralKind::ByteString,
+            SyntaxKind::Byte => LiteralKind::Byte,
+            SyntaxKind::True | SyntaxKind::False => LiteralKind::Bool,
+            _ => return None,
+        })
+    }
+}
+
+impl NameRef {
+    pub fn name(&self) -> Option<SyntaxToken> {
+        token(&self.0, SyntaxKind::Ident)
+    }
+}
+
+impl ParenExpr {
+    pub fn expr(&self) -> Option<Expr> {
+        child(&self.0)
+    }
+}
+
+impl PrefixExpr {
+    pub fn op(&self) -> Option<SyntaxToken> {
+        operator(&self.0)
+    }
+
+    pub fn expr(&self) -> Option<Expr> {
+        child(&self.0)
+    }
+}
+
+impl BinaryExpr {
+    pub fn lhs(&self) -> Option<Expr> {
+        children(&self.0).next()
+    }
+
+    pub fn rhs(&self) -> Option<Expr> {
+        children(&self.0).nth(1)
+    }
+
+    pub fn op(&self) -> Option<SyntaxToken> {
+        operator(&self.0)
+    }
+}
+
+impl CallExpr {
+    pub fn callee(&self) -> Option<Expr> {
+        child(&self.0)
+    }
+
+    pub fn arg_list(&self) -> Option<ArgList> {
+        child(&self.0)
+    }
+}
+
+impl ArgList {
+    pub fn args(&self) -> impl Iterator<Item = Expr> {
+        children(&self.0)
+    }
+}
+
+impl FieldExpr {
+    pub fn receiver(&self) -> Option<Expr> {
+        child(&self.0)
+    }
+
+    /// The name of the field, or its index in a tuple.
+    pub fn field(&self) -> Option<SyntaxToken> {
+        token(&self.0, SyntaxKind::Ident).or_else(|| token(&self.0, SyntaxKind::Int))
+    }
+}
+
+impl MethodCallExpr {
+    pub fn receiver(&self) -> Option<Expr> {
+        child(&self.0)
+    }
+
+    pub fn name(&self) -> Option<SyntaxToken> {
+        token(&self.0, SyntaxKind::Ident)
+    }
+
+    pub fn arg_list(&self) -> Option<ArgList> {
+        child(&self.0)
+    }
+}
+
+impl IfExpr {
+    pub fn condition(&self) -> Option<Expr> {
+        child(&self.0)
+    }
+
+    pub fn then_branch(&self) -> Option<Block> {
+        child(&self.0)
+    }
+
+    /// The `else` branch, which is either a block or another `if` expression.
+    pub fn else_branch(&self) -> Option<SyntaxNode> {
+        self.0
+            .children()
+            .filter(|node| matches!(node.kind(), SyntaxKind::Block | SyntaxKind::IfExpr))
+            .nth(1)
+    }
+}
+
+impl WhileExpr {
+    pub fn condition(&self) -> Option<Expr> {
+        child(&self.0)
+    }
+
+    pub fn body(&self) -> Option<Block> {
+        child(&self.0)
+    }
+}
+
+/// A piece of an interpolated string. The first fragment includes the opening quote,
+/// and the last one includes the closing quote.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InterpPart {
+    Fragment(SyntaxToken),
+    Expr(Expr),
+}
+
+impl InterpString {
+    pub fn parts(&self) -> impl Iterator<Item = InterpPart> {
+        self.0
+            .children_with_tokens()
+            .filter_map(|element| match element {
+                NodeOrToken::Token(token) if token.kind() == SyntaxKind::StringFragment => {
+                    Some(InterpPart::Fragment(token))
+                }
+                NodeOrToken::Node(node) => Expr::cast(node).map(InterpPart::Expr),
+                NodeOrToken::Token(_) => None,
+            })
+    }
+}
+
+impl ReturnExpr {
+    /// The returned value, if there is one.
+    pub fn expr(&self) -> Option<Expr> {
+        child(&self.0)
+    }
+}
+
+impl BreakExpr {
+    pub fn expr(&self) -> Option<Expr> {
+        child(&self.0)
+    }
+}
+
+impl PathType {
+    pub fn segments(&self) -> impl Iterator<Item = SyntaxToken> {
+        tokens(&self.0, SyntaxKind::Ident)
+    }
+
+    pub fn generic_args(&self) -> Option<GenericArgList> {
+        child(&self.0)
+    }
+}
+
+impl GenericArgList {
+    pub fn types(&self) -> impl Iterator<Item = Type> {
+        children(&self.0)
+    }
+}
+
+impl TupleType {
+    pub fn types(&self) -> impl Iterator<Item = Type> {
+        children(&self.0)
+    }
+}
+
+impl ParenType {
+    pub fn ty(&self) -> Option<Type> {
+        child(&self.0)
+    }
+}
+
+impl RefType {
+    pub fn is_mut(&self) -> bool {
+        token(&self.0, SyntaxKind::Mut).is_some()
+    }
+
+    pub fn ty(&self) -> Option<Type> {
+        child(&self.0)
+    }
+}
+
+fn child<N: AstNode>(node: &SyntaxNode) -> Option<N> {
+    node.children().find_map(N::cast)
+}
+
+fn children<N: AstNode>(node: &SyntaxNode) -> impl Iterator<Item = N> {
+    node.children().filter_map(N::cast)
+}
+
+fn operator(node: &SyntaxNode) -> Option<SyntaxToken> {
+    node.children_with_tokens()
+        .filter_map(|element| element.into_token())
+        .find(|token| token.kind().is_operator())
+}
+
+fn token(node: &SyntaxNode, kind: SyntaxKind) -> Option<SyntaxToken> {
+    tokens(node, kind).next()
+}
+
+fn tokens(node: &SyntaxNode, kind: SyntaxKind) -> impl Iterator<Item = SyntaxToken> {
+    node.children_with_tokens()
+        .filter_map(|element| element.into_token())
+        .filter(move |token| token.kind() == kind)
+}
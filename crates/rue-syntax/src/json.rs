@@ -0,0 +1,180 @@
+use rowan::{NodeOrToken, TextRange};
+use serde_json::{json, Value};
+
+use crate::{SyntaxElement, SyntaxNode};
+
+/// Converts a syntax tree to JSON, so that it can be used outside of Rust. Nodes
+/// have a kind, range and children, and tokens have a kind, range and text.
+pub fn to_json(node: &SyntaxNode) -> Value {
+    element_to_json(NodeOrToken::Node(node.clone()))
+}
+
+// Keys are inserted in alphabetical order, so the output is the same whether or not
+// `serde_json` preserves insertion order.
+fn element_to_json(element: SyntaxElement) -> Value {
+    match element {
+        NodeOrToken::Node(node) => json!({
+            "children": node
+                .children_with_tokens()
+                .map(element_to_json)
+                .collect::<Vec<_>>(),
+            "kind": format!("{:?}", node.kind()),
+            "range": range_to_json(node.text_range()),
+        }),
+        NodeOrToken::Token(token) => json!({
+            "kind": format!("{:?}", token.kind()),
+            "range": range_to_json(token.text_range()),
+            "text": token.text(),
+        }),
+    }
+}
+
+fn range_to_json(range: TextRange) -> Value {
+    json!([u32::from(range.start()), u32::from(range.end())])
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+    use rowan::{GreenNodeBuilder, Language};
+
+    use super::*;
+    use crate::{RueLang, SyntaxKind};
+
+    /// Builds the tree for `fn main() {}` by hand, since the parser depends on this crate.
+    fn main_fn() -> SyntaxNode {
+        let mut builder = GreenNodeBuilder::new();
+        let start = |builder: &mut GreenNodeBuilder, kind| {
+            builder.start_node(RueLang::kind_to_raw(kind));
+        };
+        let token = |builder: &mut GreenNodeBuilder, kind, text| {
+            builder.token(RueLang::kind_to_raw(kind), text);
+        };
+
+        start(&mut builder, SyntaxKind::Program);
+        start(&mut builder, SyntaxKind::Function);
+        token(&mut builder, SyntaxKind::Fn, "fn");
+        token(&mut builder, SyntaxKind::Whitespace, " ");
+        token(&mut builder, SyntaxKind::Ident, "main");
+        start(&mut builder, SyntaxKind::ParamList);
+        token(&mut builder, SyntaxKind::OpenParen, "(");
+        token(&mut builder, SyntaxKind::CloseParen, ")");
+        token(&mut builder, SyntaxKind::Whitespace, " ");
+        builder.finish_node();
+        start(&mut builder, SyntaxKind::Block);
+        token(&mut builder, SyntaxKind::OpenBrace, "{");
+        token(&mut builder, SyntaxKind::CloseBrace, "}");
+        builder.finish_node();
+        builder.finish_node();
+        builder.finish_node();
+
+        SyntaxNode::new_root(builder.finish())
+    }
+
+    #[test]
+    fn main_to_json() {
+        let json = serde_json::to_string_pretty(&to_json(&main_fn())).unwrap();
+        expect![[r#"
+            {
+              "children": [
+                {
+                  "children": [
+                    {
+                      "kind": "Fn",
+                      "range": [
+                        0,
+                        2
+                      ],
+                      "text": "fn"
+                    },
+                    {
+                      "kind": "Whitespace",
+                      "range": [
+                        2,
+                        3
+                      ],
+                      "text": " "
+                    },
+                    {
+                      "kind": "Ident",
+                      "range": [
+                        3,
+                        7
+                      ],
+                      "text": "main"
+                    },
+                    {
+                      "children": [
+                        {
+                          "kind": "OpenParen",
+                          "range": [
+                            7,
+                            8
+                          ],
+                          "text": "("
+                        },
+                        {
+                          "kind": "CloseParen",
+                          "range": [
+                            8,
+                            9
+                          ],
+                          "text": ")"
+                        },
+                        {
+                          "kind": "Whitespace",
+                          "range": [
+                            9,
+                            10
+                          ],
+                          "text": " "
+                        }
+                      ],
+                      "kind": "ParamList",
+                      "range": [
+                        7,
+                        10
+                      ]
+                    },
+                    {
+                      "children": [
+                        {
+                          "kind": "OpenBrace",
+                          "range": [
+                            10,
+                            11
+                          ],
+                          "text": "{"
+                        },
+                        {
+                          "kind": "CloseBrace",
+                          "range": [
+                            11,
+                            12
+                          ],
+                          "text": "}"
+                        }
+                      ],
+                      "kind": "Block",
+                      "range": [
+                        10,
+                        12
+                      ]
+                    }
+                  ],
+                  "kind": "Function",
+                  "range": [
+                    0,
+                    12
+                  ]
+                }
+              ],
+              "kind": "Program",
+              "range": [
+                0,
+                12
+              ]
+            }"#]]
+        .assert_eq(&json);
+    }
+}
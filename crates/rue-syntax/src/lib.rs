@@ -1,7 +1,15 @@
 use num_traits::{FromPrimitive, ToPrimitive};
 
+pub mod ast;
+
+#[cfg(feature = "serde")]
+mod json;
+mod sexpr;
 mod syntax_kind;
 
+#[cfg(feature = "serde")]
+pub use json::*;
+pub use sexpr::*;
 pub use syntax_kind::*;
 
 pub type SyntaxNode = rowan::SyntaxNode<RueLang>;
@@ -0,0 +1,38 @@
+use std::fmt::Write;
+
+use rowan::NodeOrToken;
+
+use crate::{SyntaxElement, SyntaxNode};
+
+/// Renders a syntax tree as an S-expression such as `(Program (Function (Fn "fn")))`,
+/// which is more compact than the debug output. Trivia is left out.
+pub fn to_sexpr(node: &SyntaxNode) -> String {
+    let mut output = String::new();
+    write_sexpr(&mut output, NodeOrToken::Node(node.clone()), false);
+    output
+}
+
+/// Like [`to_sexpr`], but whitespace and comments are included.
+pub fn to_sexpr_with_trivia(node: &SyntaxNode) -> String {
+    let mut output = String::new();
+    write_sexpr(&mut output, NodeOrToken::Node(node.clone()), true);
+    output
+}
+
+fn write_sexpr(output: &mut String, element: SyntaxElement, trivia: bool) {
+    match element {
+        NodeOrToken::Node(node) => {
+            write!(output, "({:?}", node.kind()).unwrap();
+            for child in node.children_with_tokens() {
+                if trivia || !child.kind().is_trivia() {
+                    output.push(' ');
+                    write_sexpr(output, child, trivia);
+                }
+            }
+            output.push(')');
+        }
+        NodeOrToken::Token(token) => {
+            write!(output, "({:?} {:?})", token.kind(), token.text()).unwrap();
+        }
+    }
+}
@@ -6,33 +6,444 @@ use num_derive::{FromPrimitive, ToPrimitive};
 pub enum SyntaxKind {
     #[default]
     Eof,
-    Error,
+    /// A character which doesn't start any token.
+    ErrorToken,
+    /// Tokens the parser skipped over, or an empty node where something was missing.
+    ErrorNode,
 
     Whitespace,
     BlockComment,
     LineComment,
+    Shebang,
 
     String,
+    Char,
+    ByteString,
+    Byte,
+    StringFragment,
     Ident,
+    Int,
+    Float,
 
     Fn,
+    Let,
+    Mut,
+    If,
+    Else,
+    While,
+    For,
+    Return,
+    Break,
+    Continue,
+    Struct,
+    Enum,
+    Pub,
+    Crate,
+    Super,
+    True,
+    False,
 
     OpenParen,
     CloseParen,
     OpenBrace,
     CloseBrace,
+    OpenBracket,
+    CloseBracket,
 
+    Hash,
+    InterpStart,
+    InterpEnd,
+    Dot,
+    Comma,
+    Colon,
+    ColonColon,
+    Semicolon,
+
+    Arrow,
+
+    Equals,
+    Not,
+
+    EqualEquals,
+    NotEquals,
+    LessThan,
     GreaterThan,
+    LessThanEquals,
+    GreaterThanEquals,
+
+    Plus,
     Minus,
+    Star,
+    Slash,
+    Percent,
+
+    PlusEquals,
+    MinusEquals,
+    StarEquals,
+    SlashEquals,
+    PercentEquals,
+
+    Amp,
+    Pipe,
+    Caret,
+    AndAnd,
+    OrOr,
 
     Program,
+    Fragment,
+    Attr,
+    Visibility,
+    Function,
+    GenericParamList,
+    TypeParam,
+    ParamList,
+    Param,
+    RetType,
+    StructItem,
+    FieldList,
+    Field,
+    EnumItem,
+    VariantList,
+    Variant,
+    TypeList,
+    Block,
+    ExprStmt,
+    LetStmt,
+    Literal,
+    ParenExpr,
+    PrefixExpr,
+    BinaryExpr,
+    CallExpr,
+    ArgList,
+    FieldExpr,
+    MethodCallExpr,
+    IfExpr,
+    WhileExpr,
+    InterpString,
+    ReturnExpr,
+    BreakExpr,
+    ContinueExpr,
+    NameRef,
+    PathType,
+    GenericArgList,
+    TupleType,
+    ParenType,
+    RefType,
 }
 
 impl SyntaxKind {
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::Eof => "end of file",
+            Self::ErrorToken => "unknown character",
+            Self::ErrorNode => "error",
+
+            Self::Whitespace => "whitespace",
+            Self::BlockComment => "block comment",
+            Self::LineComment => "line comment",
+            Self::Shebang => "shebang",
+
+            Self::String => "string literal",
+            Self::Char => "character literal",
+            Self::ByteString => "byte string literal",
+            Self::Byte => "byte literal",
+            Self::StringFragment => "string fragment",
+            Self::Ident => "identifier",
+            Self::Int => "integer literal",
+            Self::Float => "float literal",
+
+            Self::Fn => "`fn`",
+            Self::Let => "`let`",
+            Self::Mut => "`mut`",
+            Self::If => "`if`",
+            Self::Else => "`else`",
+            Self::While => "`while`",
+            Self::For => "`for`",
+            Self::Return => "`return`",
+            Self::Break => "`break`",
+            Self::Continue => "`continue`",
+            Self::Struct => "`struct`",
+            Self::Enum => "`enum`",
+            Self::Pub => "`pub`",
+            Self::Crate => "`crate`",
+            Self::Super => "`super`",
+            Self::True => "`true`",
+            Self::False => "`false`",
+
+            Self::OpenParen => "open parenthesis",
+            Self::CloseParen => "close parenthesis",
+            Self::OpenBrace => "open brace",
+            Self::CloseBrace => "close brace",
+            Self::OpenBracket => "open bracket",
+            Self::CloseBracket => "close bracket",
+
+            Self::Hash => "`#`",
+            Self::InterpStart => "`${`",
+            Self::InterpEnd => "end of interpolation",
+            Self::Dot => "`.`",
+            Self::Comma => "`,`",
+            Self::Colon => "`:`",
+            Self::ColonColon => "`::`",
+            Self::Semicolon => "`;`",
+
+            Self::Arrow => "`->`",
+
+            Self::Equals => "`=`",
+            Self::Not => "`!`",
+
+            Self::EqualEquals => "`==`",
+            Self::NotEquals => "`!=`",
+            Self::LessThan => "`<`",
+            Self::GreaterThan => "`>`",
+            Self::LessThanEquals => "`<=`",
+            Self::GreaterThanEquals => "`>=`",
+
+            Self::Plus => "`+`",
+            Self::Minus => "`-`",
+            Self::Star => "`*`",
+            Self::Slash => "`/`",
+            Self::Percent => "`%`",
+
+            Self::PlusEquals => "`+=`",
+            Self::MinusEquals => "`-=`",
+            Self::StarEquals => "`*=`",
+            Self::SlashEquals => "`/=`",
+            Self::PercentEquals => "`%=`",
+
+            Self::Amp => "`&`",
+            Self::Pipe => "`|`",
+            Self::Caret => "`^`",
+            Self::AndAnd => "`&&`",
+            Self::OrOr => "`||`",
+
+            Self::Program => "program",
+            Self::Fragment => "fragment",
+            Self::Attr => "attribute",
+            Self::Visibility => "visibility",
+            Self::Function => "function",
+            Self::GenericParamList => "generic parameter list",
+            Self::TypeParam => "type parameter",
+            Self::ParamList => "parameter list",
+            Self::Param => "parameter",
+            Self::RetType => "return type",
+            Self::StructItem => "struct",
+            Self::FieldList => "field list",
+            Self::Field => "field",
+            Self::EnumItem => "enum",
+            Self::VariantList => "variant list",
+            Self::Variant => "variant",
+            Self::TypeList => "type list",
+            Self::Block => "block",
+            Self::ExprStmt => "expression statement",
+            Self::LetStmt => "`let` statement",
+            Self::Literal => "literal",
+            Self::ParenExpr => "parenthesized expression",
+            Self::PrefixExpr => "prefix expression",
+            Self::BinaryExpr => "binary expression",
+            Self::CallExpr => "call expression",
+            Self::ArgList => "argument list",
+            Self::FieldExpr => "field access",
+            Self::MethodCallExpr => "method call",
+            Self::IfExpr => "`if` expression",
+            Self::WhileExpr => "`while` loop",
+            Self::InterpString => "interpolated string",
+            Self::ReturnExpr => "`return` expression",
+            Self::BreakExpr => "`break` expression",
+            Self::ContinueExpr => "`continue` expression",
+            Self::NameRef => "name",
+            Self::PathType => "path type",
+            Self::GenericArgList => "generic argument list",
+            Self::TupleType => "tuple type",
+            Self::ParenType => "parenthesized type",
+            Self::RefType => "reference type",
+        }
+    }
+
     pub fn is_trivia(self) -> bool {
-        matches!(
-            self,
-            SyntaxKind::Whitespace | SyntaxKind::BlockComment | SyntaxKind::LineComment
-        )
+        self.category() == Category::Trivia
+    }
+
+    /// Whether this is a keyword. Note that `true` and `false` are both keywords
+    /// and literals.
+    pub fn is_keyword(self) -> bool {
+        matches!(self.category(), Category::Keyword | Category::BoolLiteral)
+    }
+
+    pub fn is_literal(self) -> bool {
+        matches!(self.category(), Category::Literal | Category::BoolLiteral)
+    }
+
+    pub fn is_operator(self) -> bool {
+        self.category() == Category::Operator
+    }
+
+    pub fn is_punct(self) -> bool {
+        self.category() == Category::Punct
+    }
+
+    /// This match is intentionally exhaustive, so that every new kind has to be
+    /// categorized explicitly.
+    fn category(self) -> Category {
+        match self {
+            Self::Whitespace | Self::BlockComment | Self::LineComment | Self::Shebang => {
+                Category::Trivia
+            }
+
+            Self::String
+            | Self::Char
+            | Self::ByteString
+            | Self::Byte
+            | Self::StringFragment
+            | Self::Int
+            | Self::Float => Category::Literal,
+            Self::True | Self::False => Category::BoolLiteral,
+
+            Self::Fn
+            | Self::Let
+            | Self::Mut
+            | Self::If
+            | Self::Else
+            | Self::While
+            | Self::For
+            | Self::Return
+            | Self::Break
+            | Self::Continue
+            | Self::Struct
+            | Self::Enum
+            | Self::Pub
+            | Self::Crate
+            | Self::Super => Category::Keyword,
+
+            Self::OpenParen
+            | Self::CloseParen
+            | Self::OpenBrace
+            | Self::CloseBrace
+            | Self::OpenBracket
+            | Self::CloseBracket
+            | Self::Hash
+            | Self::InterpStart
+            | Self::InterpEnd
+            | Self::Dot
+            | Self::Comma
+            | Self::Colon
+            | Self::ColonColon
+            | Self::Semicolon
+            | Self::Arrow => Category::Punct,
+
+            Self::Equals
+            | Self::Not
+            | Self::EqualEquals
+            | Self::NotEquals
+            | Self::LessThan
+            | Self::GreaterThan
+            | Self::LessThanEquals
+            | Self::GreaterThanEquals
+            | Self::Plus
+            | Self::Minus
+            | Self::Star
+            | Self::Slash
+            | Self::Percent
+            | Self::PlusEquals
+            | Self::MinusEquals
+            | Self::StarEquals
+            | Self::SlashEquals
+            | Self::PercentEquals
+            | Self::Amp
+            | Self::Pipe
+            | Self::Caret
+            | Self::AndAnd
+            | Self::OrOr => Category::Operator,
+
+            Self::Eof | Self::ErrorToken | Self::ErrorNode | Self::Ident => Category::Other,
+
+            Self::Program
+            | Self::Fragment
+            | Self::Attr
+            | Self::Visibility
+            | Self::Function
+            | Self::GenericParamList
+            | Self::TypeParam
+            | Self::ParamList
+            | Self::Param
+            | Self::RetType
+            | Self::StructItem
+            | Self::FieldList
+            | Self::Field
+            | Self::EnumItem
+            | Self::VariantList
+            | Self::Variant
+            | Self::TypeList
+            | Self::Block
+            | Self::ExprStmt
+            | Self::LetStmt
+            | Self::Literal
+            | Self::ParenExpr
+            | Self::PrefixExpr
+            | Self::BinaryExpr
+            | Self::CallExpr
+            | Self::ArgList
+            | Self::FieldExpr
+            | Self::MethodCallExpr
+            | Self::IfExpr
+            | Self::WhileExpr
+            | Self::InterpString
+            | Self::ReturnExpr
+            | Self::BreakExpr
+            | Self::ContinueExpr
+            | Self::NameRef
+            | Self::PathType
+            | Self::GenericArgList
+            | Self::TupleType
+            | Self::ParenType
+            | Self::RefType => Category::Node,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Category {
+    Trivia,
+    Literal,
+    BoolLiteral,
+    Keyword,
+    Punct,
+    Operator,
+    Other,
+    Node,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_description() {
+        assert_eq!(SyntaxKind::Eof.description(), "end of file");
+        assert_eq!(SyntaxKind::Ident.description(), "identifier");
+        assert_eq!(SyntaxKind::OpenBrace.description(), "open brace");
+        assert_eq!(SyntaxKind::Fn.description(), "`fn`");
+        assert_eq!(SyntaxKind::ParamList.description(), "parameter list");
+    }
+
+    #[test]
+    fn test_classification() {
+        assert!(SyntaxKind::LineComment.is_trivia());
+        assert!(SyntaxKind::Int.is_literal());
+        assert!(SyntaxKind::While.is_keyword());
+        assert!(SyntaxKind::True.is_keyword() && SyntaxKind::True.is_literal());
+        assert!(SyntaxKind::Semicolon.is_punct());
+        assert!(SyntaxKind::AndAnd.is_operator());
+
+        for kind in [
+            SyntaxKind::Ident,
+            SyntaxKind::ErrorToken,
+            SyntaxKind::Function,
+        ] {
+            assert!(!kind.is_trivia());
+            assert!(!kind.is_literal());
+            assert!(!kind.is_keyword());
+            assert!(!kind.is_punct());
+            assert!(!kind.is_operator());
+        }
     }
 }
@@ -0,0 +1,21 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rue_parser::{
+    arbitrary::{render, Program},
+    parse_text_with_errors,
+};
+use rue_syntax::to_sexpr;
+
+// Generated programs are always valid, so they should parse without errors, and
+// formatting them shouldn't change the structure of the tree.
+fuzz_target!(|program: Program| {
+    let source = render(&program);
+    let parse = parse_text_with_errors(&source);
+    assert_eq!(parse.errors, [], "{source}");
+
+    let formatted = rue_fmt::format(&parse.node);
+    let reparse = parse_text_with_errors(&formatted);
+    assert_eq!(reparse.errors, [], "{formatted}");
+    assert_eq!(to_sexpr(&parse.node), to_sexpr(&reparse.node));
+});
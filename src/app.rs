@@ -6,14 +6,21 @@ use leptos_router::*;
 use thaw::*;
 
 mod guide;
+mod playground;
+mod syntax_tree;
 
-use crate::components::CodeBlock;
+use crate::components::{provide_color_scheme, CodeBlock, ThemeToggle};
 
 use guide::GettingStarted;
+use playground::Playground;
+use syntax_tree::SyntaxTree;
 
 #[component]
 pub fn App() -> impl IntoView {
     provide_meta_context();
+    let scheme = provide_color_scheme();
+    let theme = create_rw_signal(scheme.get_untracked().theme());
+    create_effect(move |_| theme.set(scheme.get().theme()));
 
     view! {
         <Stylesheet id="leptos" href="/pkg/rue-web.css"/>
@@ -24,7 +31,7 @@ pub fn App() -> impl IntoView {
             outside_errors.insert_with_default_key(AppError::NotFound);
             view! { <ErrorTemplate outside_errors/> }.into_view()
         }>
-            <ThemeProvider theme=Theme::dark()>
+            <ThemeProvider theme>
                 <GlobalStyle/>
                 <nav>
                     <Nav/>
@@ -33,6 +40,8 @@ pub fn App() -> impl IntoView {
                     <Routes>
                         <Route path="" view=HomePage/>
                         <Route path="guide" view=GettingStarted/>
+                        <Route path="playground" view=Playground/>
+                        <Route path="syntax-tree" view=SyntaxTree/>
                     </Routes>
                 </main>
 
@@ -64,6 +73,27 @@ fn Nav() -> impl IntoView {
 
             "Guide"
         </Button>
+        <Button
+            variant=ButtonVariant::Text
+            on_click=move |_| {
+                let navigate = use_navigate();
+                navigate("/playground", Default::default());
+            }
+        >
+
+            "Playground"
+        </Button>
+        <Button
+            variant=ButtonVariant::Text
+            on_click=move |_| {
+                let navigate = use_navigate();
+                navigate("/syntax-tree", Default::default());
+            }
+        >
+
+            "Syntax Tree"
+        </Button>
+        <ThemeToggle/>
     }
 }
 
@@ -0,0 +1,165 @@
+use leptos::*;
+use rue_lexer::LineIndex;
+use rue_parser::parse_text_with_errors;
+
+use crate::components::{CodeBlock, Underline};
+
+const INITIAL_SOURCE: &str = include_str!("../../snippets/hello_world.rue");
+
+/// The parse errors in the source, as underlines and as messages prefixed by
+/// their one-based line and column.
+fn parse_errors(source: &str) -> (Vec<Underline>, Vec<String>) {
+    let index = LineIndex::new(source);
+
+    parse_text_with_errors(source)
+        .errors
+        .into_iter()
+        .map(|error| {
+            let pos = index.line_col(error.range.start().into());
+            let message = format!("{}:{}: {}", pos.line + 1, pos.col + 1, error.message);
+            let underline = Underline {
+                span: error.range.into(),
+                message: error.message,
+            };
+            (underline, message)
+        })
+        .unzip()
+}
+
+/// The text being typed, and the source which is highlighted, which catches up
+/// with it once typing pauses.
+#[derive(Clone, Copy)]
+struct Editor {
+    input: RwSignal<String>,
+    source: RwSignal<String>,
+    pending: StoredValue<Option<TimeoutHandle>>,
+}
+
+impl Editor {
+    fn new(text: &str) -> Self {
+        Self {
+            input: create_rw_signal(text.to_string()),
+            source: create_rw_signal(text.to_string()),
+            pending: store_value(None),
+        }
+    }
+
+    // Lexing and parsing on every keystroke is wasteful, so only the latest
+    // input is processed once typing pauses.
+    fn type_text(self, text: String) {
+        self.input.set(text);
+
+        if let Some(handle) = self.pending.get_value() {
+            handle.clear();
+        }
+
+        let Self { input, source, .. } = self;
+        let handle = after_pause(move || source.set(input.get_untracked()));
+        self.pending.set_value(handle);
+    }
+}
+
+#[cfg(feature = "hydrate")]
+fn after_pause(f: impl FnOnce() + 'static) -> Option<TimeoutHandle> {
+    /// How long to wait after the last keystroke before re-highlighting.
+    const DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(150);
+
+    set_timeout_with_handle(f, DEBOUNCE).ok()
+}
+
+/// There are no keystrokes to wait for outside of the browser, so this runs
+/// right away.
+#[cfg(not(feature = "hydrate"))]
+fn after_pause(f: impl FnOnce() + 'static) -> Option<TimeoutHandle> {
+    f();
+    None
+}
+
+#[component]
+pub fn Playground() -> impl IntoView {
+    view! { <EditorView editor=Editor::new(INITIAL_SOURCE)/> }
+}
+
+#[component]
+fn EditorView(editor: Editor) -> impl IntoView {
+    let Editor { input, source, .. } = editor;
+    let errors = create_memo(move |_| parse_errors(&source.get()));
+
+    view! {
+        <div class="playground">
+            <textarea
+                class="playground-input"
+                spellcheck="false"
+                prop:value=move || input.get()
+                on:input=move |event| editor.type_text(event_target_value(&event))
+            ></textarea>
+
+            {move || {
+                view! {
+                    <CodeBlock
+                        source=source.get()
+                        show_line_numbers=true
+                        copyable=false
+                        underlines=errors.get().0
+                    />
+                }
+            }}
+
+            <ul class="playground-errors">
+                {move || {
+                    errors
+                        .get()
+                        .1
+                        .into_iter()
+                        .map(|error| view! { <li>{error}</li> })
+                        .collect::<Vec<_>>()
+                }}
+
+            </ul>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_parse_errors() {
+        assert!(parse_errors(INITIAL_SOURCE).0.is_empty());
+
+        let (underlines, messages) = parse_errors("fn main() {\n    let x = ;\n}");
+        assert_eq!(messages, ["2:13: expected expression"]);
+        assert_eq!(underlines[0].span, 24..25);
+    }
+
+    /// Types each of the texts into a new editor, then renders it.
+    #[cfg(feature = "ssr")]
+    fn render(texts: &'static [&'static str]) -> String {
+        leptos::ssr::render_to_string(move || {
+            leptos_meta::provide_meta_context();
+            let editor = Editor::new(INITIAL_SOURCE);
+            for text in texts {
+                editor.type_text(text.to_string());
+            }
+            assert_eq!(editor.source.get_untracked(), editor.input.get_untracked());
+
+            view! { <EditorView editor/> }
+        })
+        .to_string()
+    }
+
+    #[cfg(feature = "ssr")]
+    #[test]
+    fn rehighlights_typed_text() {
+        let html = render(&["fn m", "fn main() {}"]);
+        assert!(html.contains(r#"title="Ident 3..7""#));
+        assert!(!html.contains("<li"));
+
+        let html = render(&["fn main() {}", "fn mains() { x"]);
+        assert!(html.contains(r#"title="Ident 3..8""#));
+        assert!(html.contains("Ident 13..14"));
+        assert!(!html.contains(r#"title="Ident 3..7""#));
+        assert!(html.contains("1:15: expected"));
+    }
+}
@@ -0,0 +1,240 @@
+use std::ops::Range;
+
+use leptos::*;
+use rue_parser::parse_text;
+use rue_syntax::SyntaxNode;
+
+use crate::components::{use_color_scheme, CodeBlock};
+
+const INITIAL_SOURCE: &str = include_str!("../../snippets/hello_world.rue");
+
+/// A node or token in the syntax tree, labelled like its `{:#?}` output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct TreeNode {
+    label: String,
+    selection: Selection,
+    children: Vec<TreeNode>,
+}
+
+/// A selected node or token. Nodes such as a `NameRef` and its `Ident` can have the
+/// same range, so they are told apart by the child indices leading to them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Selection {
+    path: Vec<usize>,
+    range: Range<usize>,
+}
+
+fn build_tree(node: &SyntaxNode) -> TreeNode {
+    build_node(node, Vec::new())
+}
+
+fn build_node(node: &SyntaxNode, path: Vec<usize>) -> TreeNode {
+    let children = node
+        .children_with_tokens()
+        .enumerate()
+        .map(|(index, child)| {
+            let mut path = path.clone();
+            path.push(index);
+
+            match (child.as_node(), child.as_token()) {
+                (Some(node), _) => build_node(node, path),
+                (_, token) => TreeNode {
+                    label: token.map(|token| format!("{token:?}")).unwrap_or_default(),
+                    selection: Selection {
+                        path,
+                        range: child.text_range().into(),
+                    },
+                    children: Vec::new(),
+                },
+            }
+        })
+        .collect();
+
+    TreeNode {
+        label: format!("{:?}@{:?}", node.kind(), node.text_range()),
+        selection: Selection {
+            path,
+            range: node.text_range().into(),
+        },
+        children,
+    }
+}
+
+fn render_tree(node: TreeNode, selection: RwSignal<Option<Selection>>) -> View {
+    let path = node.selection.path.clone();
+    let is_selected = move || {
+        selection.with(|selection| {
+            selection
+                .as_ref()
+                .is_some_and(|selection| selection.path == path)
+        })
+    };
+
+    let selected = node.selection.clone();
+    let select = move |_| selection.set(Some(selected.clone()));
+
+    // Tokens have no children, so they don't need to be collapsible.
+    if node.children.is_empty() {
+        return view! {
+            <div class="cst-token" class:cst-selected=is_selected on:click=select>
+                {node.label}
+            </div>
+        }
+        .into_view();
+    }
+
+    view! {
+        <details class="cst-node" open=true>
+            <summary class:cst-selected=is_selected on:click=select>
+                {node.label}
+            </summary>
+            {node
+                .children
+                .into_iter()
+                .map(|child| render_tree(child, selection))
+                .collect::<Vec<_>>()}
+        </details>
+    }
+    .into_view()
+}
+
+#[component]
+pub fn SyntaxTree() -> impl IntoView {
+    let scheme = use_color_scheme();
+    let source = create_rw_signal(INITIAL_SOURCE.to_string());
+    let selection = create_rw_signal(None::<Selection>);
+
+    let on_input = move |event| {
+        source.set(event_target_value(&event));
+        selection.set(None);
+    };
+
+    view! {
+        <div class="playground">
+            <textarea
+                class="playground-input"
+                spellcheck="false"
+                prop:value=move || source.get()
+                on:input=on_input
+            ></textarea>
+
+            <div class="cst-viewer">
+                {move || {
+                    view! {
+                        <CodeBlock
+                            source=source.get()
+                            show_line_numbers=true
+                            copyable=false
+                            selection=selection.get().map(|selection| selection.range)
+                        />
+                    }
+                }}
+
+                <div class=move || format!("cst-tree {}", scheme.get().class())>
+                    {move || render_tree(build_tree(&parse_text(&source.get())), selection)}
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(node: &TreeNode, depth: usize, output: &mut Vec<String>) {
+        output.push(format!("{}{}", "  ".repeat(depth), node.label));
+        for child in &node.children {
+            labels(child, depth + 1, output);
+        }
+    }
+
+    #[test]
+    fn tree_labels() {
+        let tree = build_tree(&parse_text("fn main() {}"));
+        let mut output = Vec::new();
+        labels(&tree, 0, &mut output);
+
+        assert_eq!(
+            output,
+            [
+                "Program@0..12",
+                "  Function@0..12",
+                "    Fn@0..2 \"fn\"",
+                "    Whitespace@2..3 \" \"",
+                "    Ident@3..7 \"main\"",
+                "    ParamList@7..10",
+                "      OpenParen@7..8 \"(\"",
+                "      CloseParen@8..9 \")\"",
+                "      Whitespace@9..10 \" \"",
+                "    Block@10..12",
+                "      OpenBrace@10..11 \"{\"",
+                "      CloseBrace@11..12 \"}\"",
+            ]
+        );
+        assert_eq!(tree.children[0].children[3].selection.range, 7..10);
+    }
+
+    #[cfg(feature = "ssr")]
+    #[test]
+    fn render_labels() {
+        let html = render("fn main() {}", &[0, 3]);
+
+        let mut rest = html.as_str();
+        for label in [
+            "Program@0..12",
+            "Function@0..12",
+            "Fn@0..2",
+            "Ident@3..7",
+            "ParamList@7..10",
+            "CloseParen@8..9",
+            "Block@10..12",
+            "CloseBrace@11..12",
+        ] {
+            let (_, after) = rest.split_once(label).expect(label);
+            rest = after;
+        }
+
+        // Only the parameter list's summary is marked as selected.
+        assert_eq!(html.matches("cst-selected").count(), 1);
+        let (before, _) = html.split_once("ParamList@7..10").unwrap();
+        assert!(before
+            .rsplit_once("<summary")
+            .unwrap()
+            .1
+            .contains("cst-selected"));
+    }
+
+    /// Renders the tree of the source, with the node at the path selected.
+    #[cfg(feature = "ssr")]
+    fn render(source: &'static str, path: &'static [usize]) -> String {
+        leptos::ssr::render_to_string(move || {
+            let tree = build_tree(&parse_text(source));
+
+            let mut node = &tree;
+            for &index in path {
+                node = &node.children[index];
+            }
+
+            let selection = create_rw_signal(Some(node.selection.clone()));
+            render_tree(tree, selection)
+        })
+        .to_string()
+    }
+
+    #[cfg(feature = "ssr")]
+    #[test]
+    fn select_token_with_same_range() {
+        // The `Ident@8..9` is selected, but not the `NameRef@8..9` around it.
+        let html = render("fn f() {x}", &[0, 4, 1, 0]);
+        assert_eq!(html.matches("cst-selected").count(), 1);
+
+        let (_, after) = html.split_once("NameRef@8..9").unwrap();
+        let (ident, _) = after.split_once("Ident@8..9").unwrap();
+        assert!(ident
+            .rsplit_once("<div")
+            .unwrap()
+            .1
+            .contains("cst-selected"));
+    }
+}
@@ -1,3 +1,5 @@
 mod rue;
+mod theme;
 
 pub use rue::*;
+pub use theme::*;
@@ -2,3 +2,4 @@ mod code_block;
 mod highlight;
 
 pub use code_block::*;
+pub use highlight::Underline;
@@ -1,15 +1,112 @@
+use std::{ops::Range, time::Duration};
+
 use leptos::*;
 use thaw::*;
 
-use super::highlight::Highlight;
+use crate::components::use_color_scheme;
+
+use super::highlight::{Highlight, Underline};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CopyState {
+    Idle,
+    Copied,
+    Unavailable,
+}
+
+impl CopyState {
+    fn label(self) -> &'static str {
+        match self {
+            Self::Idle => "Copy",
+            Self::Copied => "Copied!",
+            Self::Unavailable => "Copy unavailable",
+        }
+    }
+}
 
 #[component]
-pub fn CodeBlock(source: String) -> impl IntoView {
+pub fn CodeBlock(
+    source: String,
+    #[prop(optional)] show_line_numbers: bool,
+    #[prop(default = true)] copyable: bool,
+    #[prop(optional)] underlines: Vec<Underline>,
+    #[prop(optional_no_strip)] selection: Option<Range<usize>>,
+) -> impl IntoView {
+    let scheme = use_color_scheme();
+    let copy_state = create_rw_signal(CopyState::Idle);
+    let pending = store_value(None::<TimeoutHandle>);
+
+    // The raw source is copied, rather than the highlighted markup.
+    let copy_source = source.clone();
+    let copy = move |_| {
+        let text = copy_source.clone();
+
+        spawn_local(async move {
+            let state = if copy_to_clipboard(&text).await {
+                CopyState::Copied
+            } else {
+                CopyState::Unavailable
+            };
+            copy_state.set(state);
+
+            if let Some(handle) = pending.get_value() {
+                handle.clear();
+            }
+
+            let handle = set_timeout_with_handle(
+                move || copy_state.set(CopyState::Idle),
+                Duration::from_secs(2),
+            );
+            pending.set_value(handle.ok());
+        });
+    };
+
     view! {
-        <Card>
-            <pre class="code-block">
-                <Highlight source=source/>
-            </pre>
-        </Card>
+        <div class=move || scheme.get().class()>
+            <Card>
+                {copyable
+                    .then(|| {
+                        view! {
+                            <div class="copy-button">
+                                <Button variant=ButtonVariant::Text on_click=copy>
+                                    {move || copy_state.get().label()}
+                                </Button>
+                            </div>
+                        }
+                    })}
+
+                <pre class="code-block">
+                    <Highlight
+                        source=source
+                        show_line_numbers=show_line_numbers
+                        underlines=underlines
+                        selection=selection
+                    />
+                </pre>
+            </Card>
+        </div>
+    }
+}
+
+/// Copies the text to the clipboard, returning whether it succeeded. This fails
+/// if the clipboard API is unavailable or permission is denied.
+#[cfg(feature = "hydrate")]
+async fn copy_to_clipboard(text: &str) -> bool {
+    use wasm_bindgen::prelude::wasm_bindgen;
+    use wasm_bindgen_futures::{js_sys::Promise, JsFuture};
+
+    #[wasm_bindgen(inline_js = "export function write_clipboard(text) {
+        if (!navigator.clipboard) return Promise.reject();
+        return navigator.clipboard.writeText(text);
+    }")]
+    extern "C" {
+        fn write_clipboard(text: &str) -> Promise;
     }
+
+    JsFuture::from(write_clipboard(text)).await.is_ok()
+}
+
+#[cfg(not(feature = "hydrate"))]
+async fn copy_to_clipboard(_text: &str) -> bool {
+    false
 }
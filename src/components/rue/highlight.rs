@@ -1,19 +1,35 @@
+use std::ops::Range;
+
 use leptos::*;
 use rue_lexer::{Lexer, Token, TokenKind};
 
+/// An error to underline, with the byte range it applies to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Underline {
+    pub span: Range<usize>,
+    pub message: String,
+}
+
+#[derive(Clone)]
 struct StyledToken {
     text: String,
+    span: Range<usize>,
     class: String,
+    title: String,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum Class {
     Comment,
     String,
+    Number,
     Variable,
     Type,
     Keyword,
+    Literal,
     Pair,
+    Punctuation,
+    Operator,
     Invalid,
     Other,
 }
@@ -23,10 +39,14 @@ impl ToString for Class {
         match self {
             Self::Comment => "t-comment".into(),
             Self::String => "t-string".into(),
+            Self::Number => "t-number".into(),
             Self::Variable => "t-variable".into(),
             Self::Type => "t-type".into(),
             Self::Keyword => "t-keyword".into(),
+            Self::Literal => "t-literal".into(),
             Self::Pair => "t-pair".into(),
+            Self::Punctuation => "t-punctuation".into(),
+            Self::Operator => "t-operator".into(),
             Self::Invalid => "t-invalid".into(),
             Self::Other => "t-other".into(),
         }
@@ -37,8 +57,16 @@ fn class_for_token(token: Token) -> Class {
     use TokenKind::*;
 
     match token.kind() {
-        LineComment | BlockComment { .. } => Class::Comment,
-        String { .. } => Class::String,
+        True | False => Class::Literal,
+        kind if kind.is_keyword() => Class::Keyword,
+        LineComment | BlockComment { .. } | Shebang => Class::Comment,
+        String { .. }
+        | MultilineString { .. }
+        | ByteString { .. }
+        | Char { .. }
+        | Byte { .. }
+        | StringFragment => Class::String,
+        Int { .. } | Float => Class::Number,
         Ident => {
             if token.text().chars().next().is_some_and(char::is_uppercase) {
                 Class::Type
@@ -46,26 +74,282 @@ fn class_for_token(token: Token) -> Class {
                 Class::Variable
             }
         }
-        Fn => Class::Keyword,
-        OpenParen | CloseParen | OpenBrace | CloseBrace => Class::Pair,
-        Whitespace | GreaterThan | Minus => Class::Other,
+        OpenParen | CloseParen | OpenBrace | CloseBrace | OpenBracket | CloseBracket => Class::Pair,
+        Arrow | Equals | Not | EqualEquals | NotEquals | LessThan | GreaterThan
+        | LessThanEquals | GreaterThanEquals | Plus | Minus | Star | Slash | Percent
+        | PlusEquals | MinusEquals | StarEquals | SlashEquals | PercentEquals | Amp | Pipe
+        | Caret | AndAnd | OrOr => Class::Operator,
+        InterpStart | InterpEnd => Class::Pair,
+        Hash | Dot | Comma | Colon | ColonColon | Semicolon => Class::Punctuation,
         Unknown => Class::Invalid,
+        _ => Class::Other,
+    }
+}
+
+fn styled_tokens(source: &str, underlines: &[Underline]) -> Vec<StyledToken> {
+    Lexer::new(source)
+        .map(|token| {
+            let messages: Vec<&str> = underlines
+                .iter()
+                .filter(|underline| is_underlined(token, &underline.span, source.len()))
+                .map(|underline| underline.message.as_str())
+                .collect();
+
+            let mut class = class_for_token(token).to_string();
+            let mut title = token_title(token);
+
+            if !messages.is_empty() {
+                class.push_str(" t-error-underline");
+                title = format!("{}\n{title}", messages.join("\n"));
+            }
+
+            StyledToken {
+                text: token.text().to_string(),
+                span: token.span(),
+                class,
+                title,
+            }
+        })
+        .collect()
+}
+
+/// Describes the token for its tooltip, for example `Ident 3..7`.
+fn token_title(token: Token) -> String {
+    // The fields of kinds such as strings are left out to keep it short.
+    let kind = format!("{:?}", token.kind());
+    let name = kind.split_once(' ').map_or(kind.as_str(), |(name, _)| name);
+    let span = token.span();
+    format!("{name} {}..{}", span.start, span.end)
+}
+
+/// Whether the token overlaps the span. Empty spans, such as for something
+/// missing, underline the token they are in front of, or the last token if
+/// they are at the end of the file.
+fn is_underlined(token: Token, span: &Range<usize>, len: usize) -> bool {
+    let token_span = token.span();
+
+    if span.is_empty() {
+        return token.contains(span.start) || (span.start == len && token_span.end == len);
     }
+
+    span.start < token_span.end && token_span.start < span.end
+}
+
+/// Groups the tokens by line, splitting those which span multiple lines such as
+/// whitespace and block comments. The line breaks themselves are left out.
+fn styled_lines(source: &str, underlines: &[Underline]) -> Vec<Vec<StyledToken>> {
+    let mut lines = vec![Vec::new()];
+
+    for token in styled_tokens(source, underlines) {
+        let mut start = token.span.start;
+
+        for (i, text) in token.text.split('\n').enumerate() {
+            if i > 0 {
+                lines.push(Vec::new());
+            }
+
+            let part_start = start;
+            start += text.len() + 1;
+
+            let text = text.strip_suffix('\r').unwrap_or(text);
+            if !text.is_empty() {
+                lines.last_mut().unwrap().push(StyledToken {
+                    text: text.to_string(),
+                    span: part_start..part_start + text.len(),
+                    ..token.clone()
+                });
+            }
+        }
+    }
+
+    lines
+}
+
+/// Renders the tokens, marking those within the selected range.
+fn render_tokens(tokens: Vec<StyledToken>, selection: Option<&Range<usize>>) -> Vec<View> {
+    tokens
+        .into_iter()
+        .map(|token| {
+            let is_selected = selection.is_some_and(|selection| {
+                selection.start <= token.span.start && token.span.end <= selection.end
+            });
+
+            let class = if is_selected {
+                format!("{} t-selected", token.class)
+            } else {
+                token.class
+            };
+
+            view! { <span class=class title=token.title>{token.text}</span> }.into_view()
+        })
+        .collect()
 }
 
 #[component]
-pub fn Highlight(source: String) -> impl IntoView {
-    let tokens: Vec<StyledToken> = Lexer::new(&source)
-        .map(|token| StyledToken {
-            text: token.text().to_string(),
-            class: class_for_token(token).to_string(),
+pub fn Highlight(
+    source: String,
+    #[prop(optional)] show_line_numbers: bool,
+    #[prop(optional)] underlines: Vec<Underline>,
+    #[prop(optional_no_strip)] selection: Option<Range<usize>>,
+) -> impl IntoView {
+    let selection = selection.as_ref();
+
+    if !show_line_numbers {
+        return render_tokens(styled_tokens(&source, &underlines), selection).into_view();
+    }
+
+    styled_lines(&source, &underlines)
+        .into_iter()
+        .enumerate()
+        .map(|(i, line)| {
+            view! {
+                <div class="code-line">
+                    <span class="line-number">{i + 1}</span>
+                    {render_tokens(line, selection)}
+                </div>
+            }
         })
-        .collect();
+        .collect::<Vec<_>>()
+        .into_view()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classes(source: &str) -> Vec<Class> {
+        Lexer::new(source)
+            .filter(|token| !matches!(token.kind(), TokenKind::Whitespace { .. }))
+            .map(class_for_token)
+            .collect()
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(classes("42 0xFF 0b1 3.14 1e10"), [Class::Number; 5]);
+        assert_eq!(Class::Number.to_string(), "t-number");
+    }
+
+    #[test]
+    fn operators() {
+        assert_eq!(
+            classes("+ -> == { }"),
+            [
+                Class::Operator,
+                Class::Operator,
+                Class::Operator,
+                Class::Pair,
+                Class::Pair
+            ]
+        );
+        assert_eq!(Class::Operator.to_string(), "t-operator");
+    }
+
+    #[test]
+    fn keywords() {
+        assert_eq!(
+            classes("fn let mut if else while for return struct enum"),
+            [Class::Keyword; 10]
+        );
+        assert_eq!(classes("true false"), [Class::Literal; 2]);
+    }
 
-    view! {
-        {tokens
+    #[test]
+    fn lines() {
+        let source = "fn main() {\n    /* a\n    b */\n}\n";
+        let lines = styled_lines(source, &[]);
+        assert_eq!(lines.len(), source.matches('\n').count() + 1);
+
+        let texts: Vec<String> = lines
+            .iter()
+            .map(|line| line.iter().map(|token| token.text.as_str()).collect())
+            .collect();
+        assert_eq!(texts, ["fn main() {", "    /* a", "    b */", "}", ""]);
+    }
+
+    #[test]
+    fn crlf_lines() {
+        assert_eq!(styled_lines("a\r\nb", &[]).len(), 2);
+        assert_eq!(styled_lines("a\r\nb", &[])[0][0].text, "a");
+    }
+
+    #[test]
+    fn underlines() {
+        let source = "fn (";
+        let underlines: Vec<Underline> = rue_parser::parse_text_with_errors(source)
+            .errors
+            .into_iter()
+            .map(|error| Underline {
+                span: error.range.into(),
+                message: error.message,
+            })
+            .collect();
+
+        let underlined: Vec<(String, String)> = styled_tokens(source, &underlines)
             .into_iter()
-            .map(|token| view! { <span class=token.class>{token.text}</span> })
-            .collect::<Vec<_>>()}
+            .filter(|token| token.class.contains("t-error-underline"))
+            .map(|token| (token.text, token.title))
+            .collect();
+
+        assert_eq!(
+            underlined,
+            [
+                (
+                    "(".to_string(),
+                    "expected identifier, found open parenthesis\nexpected one of close parenthesis, open brace, close brace, found end of file\nOpenParen 3..4"
+                        .to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn titles() {
+        let titles: Vec<String> = styled_tokens("fn main \"hi\"", &[])
+            .into_iter()
+            .map(|token| token.title)
+            .collect();
+
+        assert_eq!(
+            titles,
+            [
+                "Fn 0..2",
+                "Whitespace 2..3",
+                "Ident 3..7",
+                "Whitespace 7..8",
+                "String 8..12"
+            ]
+        );
+    }
+
+    #[test]
+    fn line_spans() {
+        let spans: Vec<Vec<Range<usize>>> = styled_lines("a /* b\nc */", &[])
+            .into_iter()
+            .map(|line| line.into_iter().map(|token| token.span).collect())
+            .collect();
+
+        assert_eq!(spans, [vec![0..1, 1..2, 2..6], vec![7..11]]);
+    }
+
+    #[cfg(feature = "ssr")]
+    #[test]
+    fn selection() {
+        let html = leptos::ssr::render_to_string(|| {
+            view! { <Highlight source="fn main() {}".to_string() selection=Some(7..10)/> }
+        })
+        .to_string();
+
+        let selected: Vec<&str> = html
+            .split("<span")
+            .filter(|span| span.contains("t-selected"))
+            .filter_map(|span| span.split_once("title=\"")?.1.split_once('"'))
+            .map(|(title, _)| title)
+            .collect();
+
+        assert_eq!(
+            selected,
+            ["OpenParen 7..8", "CloseParen 8..9", "Whitespace 9..10"]
+        );
     }
 }
@@ -0,0 +1,149 @@
+use leptos::*;
+use thaw::*;
+#[cfg(feature = "hydrate")]
+use wasm_bindgen::prelude::wasm_bindgen;
+
+/// The color scheme used to highlight code. This only changes presentation, so
+/// tokens are classified the same way in either scheme.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ColorScheme {
+    Light,
+    #[default]
+    Dark,
+}
+
+impl ColorScheme {
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::Light => Self::Dark,
+            Self::Dark => Self::Light,
+        }
+    }
+
+    /// The class of the container which the `t-*` classes are resolved against.
+    pub fn class(self) -> &'static str {
+        match self {
+            Self::Light => "theme-light",
+            Self::Dark => "theme-dark",
+        }
+    }
+
+    /// The theme used for the rest of the page.
+    pub fn theme(self) -> Theme {
+        match self {
+            Self::Light => Theme::light(),
+            Self::Dark => Theme::dark(),
+        }
+    }
+
+    fn from_class(class: &str) -> Option<Self> {
+        [Self::Light, Self::Dark]
+            .into_iter()
+            .find(|scheme| scheme.class() == class)
+    }
+}
+
+/// Provides the color scheme to the rest of the app, so the choice is kept
+/// when navigating between pages.
+pub fn provide_color_scheme() -> RwSignal<ColorScheme> {
+    let scheme = create_rw_signal(ColorScheme::default());
+    provide_context(scheme);
+
+    // Effects only run in the browser once the page is hydrated, so the stored
+    // choice doesn't make the markup differ from what the server rendered.
+    create_effect(move |loaded: Option<()>| {
+        if loaded.is_none() {
+            if let Some(stored) = load_color_scheme()
+                .as_deref()
+                .and_then(ColorScheme::from_class)
+            {
+                scheme.set(stored);
+            }
+        }
+        store_color_scheme(scheme.get().class());
+    });
+
+    scheme
+}
+
+pub fn use_color_scheme() -> RwSignal<ColorScheme> {
+    use_context::<RwSignal<ColorScheme>>()
+        .unwrap_or_else(|| create_rw_signal(ColorScheme::default()))
+}
+
+#[cfg(feature = "hydrate")]
+#[wasm_bindgen(inline_js = "export function load_color_scheme() {
+    try { return localStorage.getItem('color-scheme'); } catch { return null; }
+}
+
+export function store_color_scheme(scheme) {
+    try { localStorage.setItem('color-scheme', scheme); } catch {}
+}")]
+extern "C" {
+    fn load_color_scheme() -> Option<String>;
+    fn store_color_scheme(scheme: &str);
+}
+
+#[cfg(not(feature = "hydrate"))]
+fn load_color_scheme() -> Option<String> {
+    None
+}
+
+#[cfg(not(feature = "hydrate"))]
+fn store_color_scheme(_scheme: &str) {}
+
+#[component]
+pub fn ThemeToggle() -> impl IntoView {
+    let scheme = use_color_scheme();
+
+    view! {
+        <Button
+            variant=ButtonVariant::Text
+            on_click=move |_| scheme.update(|scheme| *scheme = scheme.toggled())
+        >
+
+            {move || match scheme.get() {
+                ColorScheme::Light => "Dark theme",
+                ColorScheme::Dark => "Light theme",
+            }}
+
+        </Button>
+    }
+}
+
+#[cfg(all(test, feature = "ssr"))]
+mod tests {
+    use leptos_meta::provide_meta_context;
+
+    use super::*;
+    use crate::components::CodeBlock;
+
+    /// Renders the toggle next to a code block, after clicking it `clicks` times.
+    fn render(clicks: usize) -> String {
+        leptos::ssr::render_to_string(move || {
+            provide_meta_context();
+            let scheme = provide_color_scheme();
+            for _ in 0..clicks {
+                scheme.update(|scheme| *scheme = scheme.toggled());
+            }
+
+            view! {
+                <ThemeToggle/>
+                <CodeBlock source="fn main() {}".to_string()/>
+            }
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn toggling_flips_the_class() {
+        let html = render(0);
+        assert!(html.contains("theme-dark") && html.contains("Light theme"));
+
+        let html = render(1);
+        assert!(html.contains("theme-light") && html.contains("Dark theme"));
+        assert!(!html.contains("theme-dark"));
+
+        assert!(render(2).contains("theme-dark"));
+    }
+}